@@ -2,35 +2,26 @@
 //! It enables you to write _type operators_, the functions that translates types, in Rust syntax.
 //! Please read the [TYP book](https://github.com/jerry73204/typ-book/) understand the usage.
 
-#![feature(hash_set_entry)]
-
-mod common;
-mod env;
-mod parse;
-mod trans;
-mod tyint;
-mod utils;
-mod var;
-
-use crate::{common::*, parse::ItemVec};
-
-/// The main macro that translates the TYP langauge to actual Rust implementations.
-#[proc_macro]
-pub fn typ(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ItemVec(items) = parse_macro_input!(tokens as ItemVec);
-    crate::trans::translate_items(&items)
-        .unwrap_or_else(|err| err.to_compile_error())
-        .into()
-}
+pub use typ_macros::{tyint, typ, tyuint};
 
-/// Constructs a signed integer type from an integer literal.
-#[proc_macro]
-pub fn tyint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    tyint::tyint(input)
+/// Applies a type operator value on a tuple of arguments.
+pub trait Apply<Args> {
+    type Output;
 }
 
-/// Constructs an unsigned integer type from an integer literal.
-#[proc_macro]
-pub fn tyuint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    tyint::tyuint(input)
-}
+pub type ApplyOp<Operator, Args> = <Operator as Apply<Args>>::Output;
+
+/// Implemented by type operator values taking 0 arguments.
+pub trait Operator0 {}
+
+/// Implemented by type operator values taking 1 arguments.
+pub trait Operator1 {}
+
+/// Implemented by type operator values taking 2 arguments.
+pub trait Operator2 {}
+
+/// Implemented by type operator values taking 3 arguments.
+pub trait Operator3 {}
+
+/// Implemented by type operator values taking 4 arguments.
+pub trait Operator4 {}
@@ -1,5 +0,0 @@
-use super::*;
-
-pub fn translate_struct(_struct_: &ItemStruct) -> syn::Result<TokenStream> {
-    todo!();
-}
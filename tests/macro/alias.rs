@@ -0,0 +1,45 @@
+use crate::common::*;
+use typenum::consts::*;
+
+typ! {
+    pub enum List {
+        Cons(_, List),
+        Nil,
+    }
+
+    type Pair = Cons<U1, Cons<U2, Nil>>;
+    type Single<value> = Cons<value, Nil>;
+
+    type Zero = 0u;
+    pub type Three = 1u + 2u;
+    type Six = Three + Three;
+
+    const LIMIT: Unsigned = 1024u;
+    const NEG: Integer = -3;
+    const LARGE: Bit = if LIMIT > 1000u { true } else { false };
+
+    fn BelowLimit<value>(value: Unsigned) -> Bit {
+        value < LIMIT
+    }
+
+    mod nested {
+        use super::*;
+
+        pub const DOUBLE: Unsigned = super::LIMIT * 2u;
+    }
+}
+
+#[test]
+fn test() {
+    let _: AssertSameOp<Pair, Cons<U1, Cons<U2, Nil>>> = ();
+    let _: AssertSameOp<Single<B1>, Cons<B1, Nil>> = ();
+    let _: AssertSameOp<Zero, U0> = ();
+    let _: AssertSameOp<Three, U3> = ();
+    let _: AssertSameOp<Six, U6> = ();
+    let _: AssertSameOp<LIMIT, U1024> = ();
+    let _: AssertSameOp<NEG, N3> = ();
+    let _: AssertSameOp<LARGE, B1> = ();
+    let _: AssertSameOp<BelowLimitOp<U7>, B1> = ();
+    let _: AssertSameOp<BelowLimitOp<U2048>, B0> = ();
+    let _: AssertSameOp<nested::DOUBLE, U2048> = ();
+}
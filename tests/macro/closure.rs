@@ -0,0 +1,34 @@
+use crate::common::*;
+use typenum::consts::*;
+
+typ! {
+    fn Double<n>(n: Unsigned) -> Unsigned {
+        let double = |x: Unsigned| x * 2u;
+        double(n)
+    }
+
+    fn AddScaled<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+        let scale = rhs * 2u;
+        let add_scaled = |x| x + scale;
+        add_scaled(lhs)
+    }
+
+    fn Swap<lhs, rhs>(lhs: _, rhs: _) {
+        let swap = |first, second| (second, first);
+        swap(lhs, rhs)
+    }
+
+    fn IncTwice<n>(n: Unsigned) -> Unsigned {
+        let inc = |x: Unsigned| -> Unsigned { x + 1u };
+        let once = inc(n);
+        inc(once)
+    }
+}
+
+#[test]
+fn test() {
+    let _: AssertSameOp<DoubleOp<U3>, U6> = ();
+    let _: AssertSameOp<AddScaledOp<U1, U3>, U7> = ();
+    let _: AssertSameOp<SwapOp<U1, B0>, (B0, U1)> = ();
+    let _: AssertSameOp<IncTwiceOp<U3>, U5> = ();
+}
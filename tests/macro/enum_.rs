@@ -0,0 +1,55 @@
+use crate::common::*;
+use typenum::consts::*;
+
+typ! {
+    pub enum Shape {
+        Circle(Unsigned),
+        Rect { w: Unsigned, h: Unsigned },
+        Empty,
+    }
+
+    pub enum List {
+        Cons(_, List),
+        Nil,
+    }
+
+    fn Area<shape>(shape: Shape) -> Unsigned {
+        match shape {
+            #[generics(r: Unsigned)]
+            Circle::<r> => r * r * 3u,
+            #[generics(w: Unsigned, h: Unsigned)]
+            Rect::<w, h> => w * h,
+            Empty => 0u,
+        }
+    }
+
+    fn Len<list>(list: List) -> Unsigned {
+        match list {
+            #[generics(head, tail: List)]
+            Cons::<head, tail> => Len(tail) + 1u,
+            Nil => 0u,
+        }
+    }
+}
+
+fn assert_shape<T: Shape>() {}
+
+#[test]
+fn test() {
+    assert_shape::<Circle<U1>>();
+    assert_shape::<Rect<U2, U3>>();
+    assert_shape::<Empty>();
+
+    let _: AssertSameOp<AreaOp<Circle<U2>>, U12> = ();
+    let _: AssertSameOp<AreaOp<Rect<U2, U3>>, U6> = ();
+    let _: AssertSameOp<AreaOp<Empty>, U0> = ();
+
+    let _: AssertSameOp<LenOp<Nil>, U0> = ();
+    let _: AssertSameOp<LenOp<Cons<B0, Cons<B1, Nil>>>, U2> = ();
+}
+
+#[test]
+fn compile_fail_test() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/macro/fail_enum_sealed.rs");
+}
@@ -0,0 +1,52 @@
+use crate::common::*;
+use typenum::consts::*;
+
+typ! {
+    pub enum List {
+        Cons(_, List),
+        Nil,
+    }
+
+    fn Zeros<len>() -> List
+    where
+        len: Unsigned,
+    {
+        Replicate::<len>(0u)
+    }
+
+    fn Replicate<len, value>(value: _) -> List
+    where
+        len: Unsigned,
+    {
+        if len == 0u {
+            Nil
+        } else {
+            let prev: Unsigned = len - 1u;
+            let tail = Replicate::<prev>(value);
+            Cons::<value, tail>
+        }
+    }
+
+    fn ThreeZeros() -> List {
+        Zeros::<U3>()
+    }
+
+    fn Pairs<value>(value: _) -> List {
+        Replicate::<U2>(value)
+    }
+}
+
+#[test]
+fn test() {
+    let _: AssertSameOp<ZerosOp<U0>, Nil> = ();
+    let _: AssertSameOp<ZerosOp<U2>, Cons<U0, Cons<U0, Nil>>> = ();
+    let _: AssertSameOp<ReplicateOp<U2, B1>, Cons<B1, Cons<B1, Nil>>> = ();
+    let _: AssertSameOp<ThreeZerosOp, Cons<U0, Cons<U0, Cons<U0, Nil>>>> = ();
+    let _: AssertSameOp<PairsOp<U7>, Cons<U7, Cons<U7, Nil>>> = ();
+}
+
+#[test]
+fn compile_fail_test() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/macro/fail_explicit_generics.rs");
+}
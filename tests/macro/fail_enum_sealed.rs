@@ -0,0 +1,14 @@
+use typ::typ;
+
+typ! {
+    pub enum Shape {
+        Circle(typenum::Unsigned),
+        Empty,
+    }
+}
+
+pub struct Triangle;
+
+impl Shape for Triangle {}
+
+fn main() {}
@@ -0,0 +1,39 @@
+use typ::typ;
+
+typ! {
+    fn Replicate<len, value>(value: _) -> typenum::Unsigned
+    where
+        len: typenum::Unsigned,
+    {
+        len
+    }
+
+    fn MissingGeneric<value>(value: _) {
+        Replicate(value)
+    }
+}
+
+typ! {
+    fn Zeros<len>() -> typenum::Unsigned
+    where
+        len: typenum::Unsigned,
+    {
+        len
+    }
+
+    fn ExtraGeneric<len>(len: typenum::Unsigned) {
+        Zeros::<len, len>()
+    }
+}
+
+typ! {
+    fn Double<value>(value: typenum::Unsigned) -> typenum::Unsigned {
+        value * 2u
+    }
+
+    fn ExtraArgument<value>(value: typenum::Unsigned) {
+        Double(value, value)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use typ::typ;
+
+pub struct Cons<Head, Tail>(Head, Tail);
+pub struct Nil;
+
+typ! {
+    fn Count<list>(list: _) -> typenum::Unsigned {
+        let mut count = 0u;
+        for _ in list {
+            count = count + 1u;
+        }
+        count
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use typ::typ;
+
+pub struct Cons<Head, Tail>(Head, Tail);
+pub struct Nil;
+
+typ! {
+    fn IsNil<list>(list: _) -> typenum::Bit {
+        if let Cons(_, _) = list {
+            false
+        } else {
+            true
+        }
+    }
+}
+
+fn main() {}
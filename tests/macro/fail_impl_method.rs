@@ -0,0 +1,21 @@
+use typ::typ;
+
+typ! {
+    pub struct Unit {}
+
+    pub trait Container {
+        fn Len(self) -> typenum::Unsigned;
+    }
+
+    impl Container for Unit {
+        fn Len(self) -> typenum::Unsigned {
+            0u
+        }
+
+        fn Size(self) -> typenum::Unsigned {
+            0u
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use typ::typ;
+
+typ! {
+    pub struct Unit {}
+
+    pub trait Container {
+        fn Len(self) -> typenum::Unsigned;
+        fn Get<index>(self, index: typenum::Unsigned) -> _;
+    }
+
+    impl Container for Unit {
+        fn Len(self) -> typenum::Unsigned {
+            0u
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use typ::typ;
+
+typ! {
+    pub enum List {
+        Cons(_, List),
+        Nil,
+    }
+
+    fn HeadIs<n, list>(n: typenum::Unsigned, list: List) -> typenum::Unsigned {
+        match list {
+            Cons(0u, _) => 0u,
+            Cons(n, _) => 1u,
+            _ => 2u,
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,76 @@
+use typ::typ;
+
+typ! {
+    pub struct Wrapper { value: _ }
+
+    pub trait Indexed {
+        fn Pick<index>(self) -> _;
+    }
+
+    impl<value> Indexed for Wrapper<value> {
+        fn Pick<index>(self) -> _ {
+            value
+        }
+    }
+
+    fn MissingGeneric<wrapper>(wrapper: Indexed) {
+        wrapper.Pick()
+    }
+}
+
+typ! {
+    pub struct Left {}
+    pub struct Right {}
+
+    impl self::Shape for Left {
+        fn Size(self) {
+            1u
+        }
+    }
+
+    impl self::shapes::Shape for Right {
+        fn Size(self) {
+            2u
+        }
+    }
+
+    fn Ambiguous<value>(value: _) {
+        value.Size()
+    }
+}
+
+typ! {
+    pub struct Counter { count: _ }
+
+    pub trait Steps {
+        fn Step<amount>(self, amount: typenum::Unsigned) -> _;
+    }
+
+    impl<count> Steps for Counter<count> {
+        fn Step<amount>(self, amount: typenum::Unsigned) -> _ {
+            count + amount
+        }
+    }
+
+    fn WrongArity<counter>(counter: Steps) {
+        counter.Step(1u, 2u)
+    }
+}
+
+typ! {
+    fn Double<value>(value: typenum::Unsigned) -> typenum::Unsigned {
+        value * 2u
+    }
+
+    fn NoReceiver<value>(value: typenum::Unsigned) {
+        value.Double()
+    }
+}
+
+typ! {
+    fn NotImported<value>(value: typenum::Unsigned) {
+        value.Halve()
+    }
+}
+
+fn main() {}
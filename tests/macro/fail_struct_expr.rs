@@ -0,0 +1,27 @@
+use typ::typ;
+
+typ! {
+    pub struct Point { x: typenum::Integer, y: typenum::Integer }
+
+    fn Missing<x>(x: typenum::Integer) {
+        Point { x }
+    }
+}
+
+typ! {
+    pub struct Point2 { x: typenum::Integer, y: typenum::Integer }
+
+    fn Extra<x, y>(x: typenum::Integer, y: typenum::Integer) {
+        Point2 { x, y, z: x }
+    }
+}
+
+typ! {
+    pub struct Point3 { x: typenum::Integer, y: typenum::Integer }
+
+    fn Duplicated<x, y>(x: typenum::Integer, y: typenum::Integer) {
+        Point3 { x, y, x: y }
+    }
+}
+
+fn main() {}
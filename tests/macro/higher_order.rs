@@ -0,0 +1,127 @@
+use crate::common::*;
+use typ::{ApplyOp, Operator1, Operator2};
+use typenum::consts::*;
+
+typ! {
+    pub enum List {
+        Cons(_, List),
+        Nil,
+    }
+
+    fn Double<n>(n: Unsigned) -> Unsigned {
+        n * 2u
+    }
+
+    fn Plus<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+        lhs + rhs
+    }
+
+    fn ApplyTwice<f, x>(f: Operator1, x: _) {
+        let once = f(x);
+        f(once)
+    }
+
+    fn Map<list, f>(list: List, f: Operator1) -> List {
+        match list {
+            Cons::<head, tail> => {
+                let mapped_head = f(head);
+                let mapped_tail = Map(tail, f);
+                Cons::<mapped_head, mapped_tail>
+            }
+            Nil => Nil,
+        }
+    }
+
+    fn Fold<list, init, f>(list: List, init: _, f: Operator2) {
+        let mut acc = init;
+        for item in list {
+            acc = f(acc, item);
+        }
+        acc
+    }
+
+    fn SumDoubled<list>(list: List) -> Unsigned {
+        let double = |x: Unsigned| x * 2u;
+        let doubled = Map(list, double);
+        Fold(doubled, 0u, Plus)
+    }
+
+    fn AddAll<list, n>(list: List, n: Unsigned) -> List {
+        Map(list, Plus.curry(n))
+    }
+
+    fn IncThenDouble<x>(x: Unsigned) -> Unsigned {
+        let inc = Plus.curry(1u);
+        let inc_then_double = compose(Double, inc);
+        inc_then_double(x)
+    }
+
+    fn DoubleSum<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+        let double_sum = compose(Double, Plus);
+        double_sum(lhs, rhs)
+    }
+
+    fn QuadrupleAll<list>(list: List) -> List {
+        let quadruple = compose(Double, Double);
+        Map(list, quadruple)
+    }
+}
+
+#[test]
+fn test() {
+    let _: AssertSameOp<ApplyTwiceOp<DoubleFn, U3>, U12> = ();
+
+    let _: AssertSameOp<MapOp<Nil, DoubleFn>, Nil> = ();
+    let _: AssertSameOp<MapOp<Cons<U1, Cons<U2, Nil>>, DoubleFn>, Cons<U2, Cons<U4, Nil>>> = ();
+
+    let _: AssertSameOp<FoldOp<Cons<U1, Cons<U2, Nil>>, U0, PlusFn>, U3> = ();
+
+    let _: AssertSameOp<SumDoubledOp<Cons<U1, Cons<U2, Nil>>>, U6> = ();
+
+    let _: AssertSameOp<AddAllOp<Cons<U1, Cons<U2, Nil>>, U3>, Cons<U4, Cons<U5, Nil>>> = ();
+    let _: AssertSameOp<IncThenDoubleOp<U4>, U10> = ();
+    let _: AssertSameOp<DoubleSumOp<U2, U3>, U10> = ();
+    let _: AssertSameOp<QuadrupleAllOp<Cons<U1, Cons<U2, Nil>>>, Cons<U4, Cons<U8, Nil>>> = ();
+    let _: AssertSameOp<ApplyOp<PlusFn, (U2, U5)>, U7> = ();
+}
+
+// operators may use the names of the built-in curry and compose
+#[allow(non_camel_case_types)]
+mod shadow_test {
+    use super::*;
+
+    typ! {
+        pub struct Counter {
+            count: Unsigned,
+        }
+
+        pub trait Advance {
+            fn curry<step>(self, step: Unsigned) -> Unsigned;
+        }
+
+        impl<count: Unsigned> Advance for Counter<count> {
+            fn curry<step>(self, step: Unsigned) -> Unsigned {
+                count + step
+            }
+        }
+
+        fn compose<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+            lhs * 10u + rhs
+        }
+
+        fn AdvanceBy<count, step>(count: Unsigned, step: Unsigned) -> Unsigned {
+            let counter = Counter { count };
+            counter.curry(step)
+        }
+
+        fn Digits<high, low>(high: Unsigned, low: Unsigned) -> Unsigned {
+            compose(high, low)
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<AdvanceByOp<U3, U4>, U7> = ();
+        let _: AssertSameOp<DigitsOp<U1, U2>, U12> = ();
+    }
+}
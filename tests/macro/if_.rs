@@ -92,3 +92,62 @@ fn test() {
     let _: AssertSameOp<IfTest6Op<P5, P2>, P2> = ();
     let _: AssertSameOp<IfTest6Op<P7, P9>, P3> = ();
 }
+
+mod if_let_test {
+    use super::*;
+
+    typ! {
+        pub enum List {
+            Cons(_, List),
+            Nil,
+        }
+
+        fn Head<list>(list: List) {
+            if let Cons::<head, _> = list {
+                head
+            } else {
+                ()
+            }
+        }
+
+        fn IsNil<list>(list: List) -> Bit {
+            let mut is_nil = true;
+            if let Cons(_, _) = list {
+                is_nil = false;
+            }
+            is_nil
+        }
+
+        fn Tail<list>(list: List) -> List {
+            let Cons(_, tail) = list else { Nil };
+            tail
+        }
+
+        fn SecondOrZero<list>(list: List) {
+            let Cons(_, tail) = list else { 0u };
+            let Cons(second, _) = tail else { 0u };
+            second
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<HeadOp<Nil>, ()> = ();
+        let _: AssertSameOp<HeadOp<Cons<U1, Nil>>, U1> = ();
+
+        let _: AssertSameOp<IsNilOp<Nil>, B1> = ();
+        let _: AssertSameOp<IsNilOp<Cons<U1, Nil>>, B0> = ();
+
+        let _: AssertSameOp<TailOp<Nil>, Nil> = ();
+        let _: AssertSameOp<TailOp<Cons<U1, Cons<U2, Nil>>>, Cons<U2, Nil>> = ();
+
+        let _: AssertSameOp<SecondOrZeroOp<Cons<U1, Nil>>, U0> = ();
+        let _: AssertSameOp<SecondOrZeroOp<Cons<U1, Cons<U2, Nil>>>, U2> = ();
+    }
+}
+
+#[test]
+fn compile_fail_test() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/macro/fail_if_let.rs");
+}
@@ -0,0 +1,89 @@
+use crate::common::*;
+use typenum::consts::*;
+
+pub trait Len {
+    type Output;
+}
+
+pub trait Shape {}
+
+pub trait Area {
+    type Output;
+}
+
+pub trait Scale<Factor> {
+    type Output;
+}
+
+typ! {
+    pub enum List {
+        Cons(_, List),
+        Nil,
+    }
+
+    pub struct Square { side: Unsigned }
+
+    impl Len for Nil {
+        fn Len(self) -> Unsigned {
+            0u
+        }
+    }
+
+    impl<head, tail: List> Len for Cons<head, tail> {
+        fn Len(self) -> Unsigned {
+            tail.Len() + 1u
+        }
+    }
+
+    impl<side: Unsigned> Shape for Square<side> {
+        fn Area(self) -> Unsigned {
+            side * side
+        }
+
+        fn Scale<factor>(self, factor: Unsigned) -> SquareMarker {
+            Square { side: side * factor }
+        }
+    }
+}
+
+fn assert_shape<T: Shape>() {}
+
+#[test]
+fn test() {
+    let _: AssertSameOp<<Nil as Len>::Output, U0> = ();
+    let _: AssertSameOp<<Cons<B0, Cons<B1, Nil>> as Len>::Output, U2> = ();
+
+    assert_shape::<Square<U3>>();
+    let _: AssertSameOp<<Square<U3> as Area>::Output, U9> = ();
+    let _: AssertSameOp<<Square<U3> as Scale<U2>>::Output, Square<U6>> = ();
+}
+
+mod parent_trait_test {
+    use crate::common::*;
+    use typenum::consts::*;
+
+    typ! {
+        pub struct Rectangle { width: Unsigned, height: Unsigned }
+
+        impl<width: Unsigned, height: Unsigned> super::Shape for Rectangle<width, height> {
+            fn Area(self) -> Unsigned {
+                width * height
+            }
+        }
+
+        fn TotalArea<lhs, rhs>(lhs: _, rhs: _) -> Unsigned {
+            lhs.Area() + rhs.Area()
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<TotalAreaOp<Rectangle<U2, U3>, super::Square<U2>>, U10> = ();
+    }
+}
+
+#[test]
+fn compile_fail_test() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/macro/fail_impl_method.rs");
+}
@@ -0,0 +1,239 @@
+use crate::common::*;
+use typenum::consts::*;
+
+typ! {
+    fn SumBelow<n>(n: Unsigned) -> Unsigned {
+        let mut acc = 0u;
+        let mut i = 0u;
+        while i < n {
+            acc = acc + i;
+            i = i + 1u;
+        }
+        acc
+    }
+
+    fn Log2<n>(n: Unsigned) -> Unsigned {
+        let mut value = n;
+        let mut count = 0u;
+        while value > 1u {
+            value = value / 2u;
+            count = count + 1u;
+        }
+        count
+    }
+
+    fn CountEven<n>(n: Unsigned) -> Unsigned {
+        let mut count = 0u;
+        let mut i = 0u;
+        while i < n {
+            if i % 2u == 0u {
+                count = count + 1u;
+            }
+            i = i + 1u;
+        }
+        count
+    }
+}
+
+#[test]
+fn test() {
+    let _: AssertSameOp<SumBelowOp<U0>, U0> = ();
+    let _: AssertSameOp<SumBelowOp<U4>, U6> = ();
+    let _: AssertSameOp<SumBelowOp<U5>, U10> = ();
+
+    let _: AssertSameOp<Log2Op<U1>, U0> = ();
+    let _: AssertSameOp<Log2Op<U8>, U3> = ();
+    let _: AssertSameOp<Log2Op<U9>, U3> = ();
+
+    let _: AssertSameOp<CountEvenOp<U0>, U0> = ();
+    let _: AssertSameOp<CountEvenOp<U5>, U3> = ();
+}
+
+mod for_test {
+    use super::*;
+
+    typ! {
+        enum List {
+            Cons(_, List),
+            Nil,
+        }
+
+        fn SumList<list>(list: List) -> Unsigned {
+            let mut acc = 0u;
+            for item in list {
+                acc = acc + item;
+            }
+            acc
+        }
+
+        fn DotProduct<pairs>(pairs: List) -> Unsigned {
+            let mut acc = 0u;
+            for (lhs, rhs) in pairs {
+                acc = acc + lhs * rhs;
+            }
+            acc
+        }
+
+        fn Last<list>(list: List) {
+            let mut last = ();
+            let item = 0u;
+            for item in list {
+                last = item;
+            }
+            (last, item)
+        }
+
+        fn Factorial<n>(n: Unsigned) -> Unsigned {
+            let mut acc = 1u;
+            for i in 1u..=n {
+                acc = acc * i;
+            }
+            acc
+        }
+
+        fn CountPairs<n>(n: Unsigned) -> Unsigned {
+            let mut count = 0u;
+            for i in 0u..n {
+                for _ in 0u..i {
+                    count = count + 1u;
+                }
+            }
+            count
+        }
+
+        fn SumFromMinusTwo<n>(n: Integer) -> Integer {
+            let mut acc = 0;
+            for i in -2..n {
+                acc = acc + i;
+            }
+            acc
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<SumListOp<Nil>, U0> = ();
+        let _: AssertSameOp<SumListOp<Cons<U1, Cons<U2, Cons<U3, Nil>>>>, U6> = ();
+
+        let _: AssertSameOp<DotProductOp<Cons<(U2, U3), Cons<(U4, U5), Nil>>>, U26> = ();
+
+        let _: AssertSameOp<LastOp<Nil>, ((), U0)> = ();
+        let _: AssertSameOp<LastOp<Cons<U3, Cons<U5, Nil>>>, (U5, U0)> = ();
+
+        let _: AssertSameOp<FactorialOp<U0>, U1> = ();
+        let _: AssertSameOp<FactorialOp<U4>, U24> = ();
+
+        let _: AssertSameOp<CountPairsOp<U4>, U6> = ();
+
+        let _: AssertSameOp<SumFromMinusTwoOp<N2>, Z0> = ();
+        let _: AssertSameOp<SumFromMinusTwoOp<P2>, N2> = ();
+    }
+}
+
+mod for_declared_list_test {
+    use super::*;
+
+    typ! {
+        enum Stack {
+            Push(_, Stack),
+            Empty,
+        }
+
+        enum Row {
+            Next(_, Row),
+            End,
+        }
+
+        fn CountStack<stack>(stack: Stack) -> Unsigned {
+            let mut count = 0u;
+            for _ in stack {
+                count = count + 1u;
+            }
+            count
+        }
+
+        fn SumRow<row>(row: Row) -> Unsigned {
+            let mut acc = 0u;
+            for item in row {
+                acc = acc + item;
+            }
+            acc
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<CountStackOp<Empty>, U0> = ();
+        let _: AssertSameOp<CountStackOp<Push<U4, Push<U7, Empty>>>, U2> = ();
+
+        let _: AssertSameOp<SumRowOp<End>, U0> = ();
+        let _: AssertSameOp<SumRowOp<Next<U4, Next<U7, End>>>, U11> = ();
+    }
+}
+
+#[test]
+fn compile_fail_test() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/macro/fail_for_list.rs");
+}
+
+mod loop_test {
+    use super::*;
+
+    typ! {
+        fn FirstPowerAbove<n>(n: Unsigned) -> Unsigned {
+            let mut power = 1u;
+            loop {
+                if power > n {
+                    break power;
+                }
+                power = power * 2u;
+            }
+        }
+
+        fn CollatzSteps<n>(n: Unsigned) -> Unsigned {
+            let mut value = n;
+            let mut steps = 0u;
+            loop {
+                if value == 1u {
+                    break;
+                }
+                if value % 2u == 0u {
+                    value = value / 2u;
+                } else {
+                    value = value * 3u + 1u;
+                }
+                steps = steps + 1u;
+            }
+            steps
+        }
+
+        fn SumOdd<n>(n: Unsigned) -> Unsigned {
+            let mut i = 0u;
+            let mut acc = 0u;
+            loop {
+                if i == n {
+                    break acc;
+                }
+                i = i + 1u;
+                if i % 2u == 0u {
+                    continue;
+                }
+                acc = acc + i;
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<FirstPowerAboveOp<U0>, U1> = ();
+        let _: AssertSameOp<FirstPowerAboveOp<U5>, U8> = ();
+        let _: AssertSameOp<FirstPowerAboveOp<U8>, U16> = ();
+
+        let _: AssertSameOp<CollatzStepsOp<U1>, U0> = ();
+        let _: AssertSameOp<CollatzStepsOp<U6>, U8> = ();
+
+        let _: AssertSameOp<SumOddOp<U0>, U0> = ();
+        let _: AssertSameOp<SumOddOp<U6>, U9> = ();
+    }
+}
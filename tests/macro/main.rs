@@ -1,6 +1,17 @@
+mod alias;
 mod binop;
+mod closure;
 mod common;
+mod enum_;
+mod explicit_generics;
+mod higher_order;
 mod if_;
+mod impl_;
+mod loop_;
 mod match_;
 mod misc;
+mod mod_;
 mod recursion;
+mod return_;
+mod struct_;
+mod trait_;
@@ -125,8 +125,379 @@ mod attributes_test {
     }
 }
 
+mod literal_test {
+    use super::*;
+
+    typ! {
+        fn Digit<value>(value: Unsigned) -> Unsigned {
+            match value {
+                0u => 10u,
+                1u => 20u,
+                2u => 30u,
+            }
+        }
+
+        fn ToUnsigned<bit>(bit: Bit) -> Unsigned {
+            match bit {
+                true => 1u,
+                false => 0u,
+            }
+        }
+
+        fn Sign<value>(value: Integer) -> Integer {
+            match value {
+                -2 => -1,
+                -1 => -1,
+                0 => 0,
+                1i => 1,
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<DigitOp<U0>, U10> = ();
+        let _: AssertSameOp<DigitOp<U1>, U20> = ();
+        let _: AssertSameOp<DigitOp<U2>, U30> = ();
+        let _: AssertSameOp<ToUnsignedOp<B1>, U1> = ();
+        let _: AssertSameOp<ToUnsignedOp<B0>, U0> = ();
+        let _: AssertSameOp<SignOp<N2>, N1> = ();
+        let _: AssertSameOp<SignOp<N1>, N1> = ();
+        let _: AssertSameOp<SignOp<Z0>, Z0> = ();
+        let _: AssertSameOp<SignOp<P1>, P1> = ();
+    }
+}
+
+mod wildcard_test {
+    use super::*;
+
+    typ! {
+        pub enum Color {
+            Red,
+            Green,
+            Blue,
+        }
+
+        pub enum Stack {
+            Push(Unsigned, Stack),
+            Empty,
+        }
+
+        fn IsZero<value>(value: Unsigned) -> Bit {
+            match value {
+                0u => true,
+                _ => false,
+            }
+        }
+
+        fn AtLeastTwo<value>(value: Unsigned) -> Unsigned {
+            match value {
+                0u => 2u,
+                1u => 2u,
+                n => n,
+            }
+        }
+
+        fn OrDefault<default, value>(default: Unsigned, value: Unsigned) -> Unsigned {
+            match value {
+                0u => default,
+                default => default,
+            }
+        }
+
+        fn IsRed<color>(color: Color) -> Bit {
+            match color {
+                Red => true,
+                _ => false,
+            }
+        }
+
+        fn IsSingleton<stack>(stack: Stack) -> Bit {
+            match stack {
+                Push::<_, Empty> => true,
+                _ => false,
+            }
+        }
+
+        fn And<lhs, rhs>(lhs: Bit, rhs: Bit) -> Bit {
+            match (lhs, rhs) {
+                (true, true) => true,
+                _ => false,
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<IsZeroOp<U0>, B1> = ();
+        let _: AssertSameOp<IsZeroOp<U1>, B0> = ();
+        let _: AssertSameOp<IsZeroOp<U6>, B0> = ();
+        let _: AssertSameOp<AtLeastTwoOp<U0>, U2> = ();
+        let _: AssertSameOp<AtLeastTwoOp<U1>, U2> = ();
+        let _: AssertSameOp<AtLeastTwoOp<U2>, U2> = ();
+        let _: AssertSameOp<AtLeastTwoOp<U7>, U7> = ();
+        let _: AssertSameOp<OrDefaultOp<U5, U0>, U5> = ();
+        let _: AssertSameOp<OrDefaultOp<U5, U3>, U3> = ();
+        let _: AssertSameOp<IsRedOp<Red>, B1> = ();
+        let _: AssertSameOp<IsRedOp<Green>, B0> = ();
+        let _: AssertSameOp<IsRedOp<Blue>, B0> = ();
+        let _: AssertSameOp<IsSingletonOp<Empty>, B0> = ();
+        let _: AssertSameOp<IsSingletonOp<Push<U1, Empty>>, B1> = ();
+        let _: AssertSameOp<IsSingletonOp<Push<U1, Push<U2, Empty>>>, B0> = ();
+        let _: AssertSameOp<AndOp<B1, B1>, B1> = ();
+        let _: AssertSameOp<AndOp<B1, B0>, B0> = ();
+        let _: AssertSameOp<AndOp<B0, B1>, B0> = ();
+        let _: AssertSameOp<AndOp<B0, B0>, B0> = ();
+    }
+}
+
+mod or_pattern_test {
+    use super::*;
+
+    pub trait Animal {}
+
+    pub struct Dog;
+    impl Animal for Dog {}
+
+    pub struct Cat;
+    impl Animal for Cat {}
+
+    pub struct Bird;
+    impl Animal for Bird {}
+
+    typ! {
+        fn Legs<animal>(animal: Animal) -> Unsigned {
+            match animal {
+                Dog | Cat => 4u,
+                Bird => 2u,
+            }
+        }
+
+        fn IsSmall<value>(value: Unsigned) -> Bit {
+            match value {
+                0u | 1u | 2u => true,
+                _ => false,
+            }
+        }
+
+        fn Other<lhs, rhs>(lhs: Bit, rhs: Bit) -> Bit {
+            match (lhs, rhs) {
+                (x, false) | (false, x) => x,
+                _ => true,
+            }
+        }
+
+        fn Category<value>(value: Unsigned) -> Unsigned {
+            let mut category = 0u;
+            match value {
+                0u | 1u => {
+                    category = 1u;
+                }
+                _ => {
+                    category = 2u;
+                }
+            }
+            category
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<LegsOp<Dog>, U4> = ();
+        let _: AssertSameOp<LegsOp<Cat>, U4> = ();
+        let _: AssertSameOp<LegsOp<Bird>, U2> = ();
+        let _: AssertSameOp<IsSmallOp<U0>, B1> = ();
+        let _: AssertSameOp<IsSmallOp<U2>, B1> = ();
+        let _: AssertSameOp<IsSmallOp<U3>, B0> = ();
+        let _: AssertSameOp<OtherOp<B1, B0>, B1> = ();
+        let _: AssertSameOp<OtherOp<B0, B1>, B1> = ();
+        let _: AssertSameOp<OtherOp<B0, B0>, B0> = ();
+        let _: AssertSameOp<OtherOp<B1, B1>, B1> = ();
+        let _: AssertSameOp<CategoryOp<U0>, U1> = ();
+        let _: AssertSameOp<CategoryOp<U1>, U1> = ();
+        let _: AssertSameOp<CategoryOp<U5>, U2> = ();
+    }
+}
+
+mod guard_test {
+    use super::*;
+
+    typ! {
+        pub enum Boxed {
+            Wrap(Unsigned),
+            Nothing,
+        }
+
+        fn Classify<value>(value: Unsigned) -> Unsigned {
+            match value {
+                0u => 0u,
+                n if n > 3u => 2u,
+                _ => 1u,
+            }
+        }
+
+        fn Unbox<boxed>(boxed: Boxed) -> Unsigned {
+            match boxed {
+                #[generics(n: Unsigned)]
+                Wrap::<n> if n > 3u => 3u,
+                #[generics(n: Unsigned)]
+                Wrap::<n> => n,
+                Nothing => 0u,
+            }
+        }
+
+        fn Bucket<value>(value: Unsigned) -> Unsigned {
+            let mut bonus = 0u;
+            let bucket = match value {
+                0u => 0u,
+                n if n > 100u => {
+                    bonus = 1u;
+                    4u
+                }
+                n if n > 10u => 3u,
+                n if n > 5u => 2u,
+                n if n > 1u => {
+                    bonus = 2u;
+                    1u
+                }
+                _ => 1u,
+            };
+            bucket + bonus
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<ClassifyOp<U0>, U0> = ();
+        let _: AssertSameOp<ClassifyOp<U2>, U1> = ();
+        let _: AssertSameOp<ClassifyOp<U3>, U1> = ();
+        let _: AssertSameOp<ClassifyOp<U4>, U2> = ();
+        let _: AssertSameOp<ClassifyOp<U9>, U2> = ();
+        let _: AssertSameOp<UnboxOp<Wrap<U2>>, U2> = ();
+        let _: AssertSameOp<UnboxOp<Wrap<U7>>, U3> = ();
+        let _: AssertSameOp<UnboxOp<Nothing>, U0> = ();
+        let _: AssertSameOp<BucketOp<U0>, U0> = ();
+        let _: AssertSameOp<BucketOp<U200>, U5> = ();
+        let _: AssertSameOp<BucketOp<U50>, U3> = ();
+        let _: AssertSameOp<BucketOp<U7>, U2> = ();
+        let _: AssertSameOp<BucketOp<U3>, U3> = ();
+        let _: AssertSameOp<BucketOp<U1>, U1> = ();
+    }
+}
+
+mod implicit_binding_test {
+    use super::*;
+
+    struct Alice<X>(X);
+    struct Bob<X>(X);
+
+    typ! {
+        pub enum Chain {
+            Link(Unsigned, Chain),
+            End,
+        }
+
+        fn Compare<lhs, rhs>(lhs: _, rhs: _) {
+            let lval = match lhs {
+                Alice::<val> => val,
+                Bob::<val> => val,
+            };
+
+            // lval is defined, so the patterns capture it
+            match rhs {
+                Alice::<lval> => (),
+                Bob::<lval> => (),
+            }
+        }
+
+        fn Sum<chain>(chain: Chain) -> Unsigned {
+            match chain {
+                Link::<head, tail: Chain> => head + Sum(tail),
+                End => 0u,
+            }
+        }
+
+        fn Order<pair>(pair: _) -> _ {
+            match pair {
+                (a, b) if a > b => (b, a),
+                (b, a) => (b, a),
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<CompareOp<Alice<B0>, Alice<B0>>, ()> = ();
+        let _: AssertSameOp<CompareOp<Alice<B0>, Bob<B0>>, ()> = ();
+        let _: AssertSameOp<CompareOp<Bob<B1>, Alice<B1>>, ()> = ();
+        let _: AssertSameOp<CompareOp<Bob<B1>, Bob<B1>>, ()> = ();
+        let _: AssertSameOp<SumOp<End>, U0> = ();
+        let _: AssertSameOp<SumOp<Link<U2, Link<U3, End>>>, U5> = ();
+        let _: AssertSameOp<OrderOp<(U3, U1)>, (U1, U3)> = ();
+        let _: AssertSameOp<OrderOp<(U1, U3)>, (U1, U3)> = ();
+    }
+}
+
+mod destructure_test {
+    use super::*;
+
+    typ! {
+        pub enum Seq {
+            Cons(_, Seq),
+            Nil,
+        }
+
+        pub enum Shape {
+            Square(Unsigned),
+            Rect { w: Unsigned, h: Unsigned },
+        }
+
+        fn First<seq>(seq: Seq) -> _ {
+            match seq {
+                Cons(head, ..) => head,
+                Nil => (),
+            }
+        }
+
+        fn Second<seq>(seq: Seq) -> _ {
+            match seq {
+                Cons(_, Cons(second, _)) => second,
+                _ => (),
+            }
+        }
+
+        fn Width<shape>(shape: Shape) -> Unsigned {
+            match shape {
+                Square(side) => side,
+                Rect { w, .. } => w,
+            }
+        }
+
+        fn Area<shape>(shape: Shape) -> Unsigned {
+            match shape {
+                Square(side) => side * side,
+                Rect { h: height, w: width } => width * height,
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<FirstOp<Nil>, ()> = ();
+        let _: AssertSameOp<FirstOp<Cons<U1, Nil>>, U1> = ();
+        let _: AssertSameOp<SecondOp<Nil>, ()> = ();
+        let _: AssertSameOp<SecondOp<Cons<U1, Nil>>, ()> = ();
+        let _: AssertSameOp<SecondOp<Cons<U1, Cons<U2, Nil>>>, U2> = ();
+        let _: AssertSameOp<WidthOp<Square<U3>>, U3> = ();
+        let _: AssertSameOp<WidthOp<Rect<U2, U5>>, U2> = ();
+        let _: AssertSameOp<AreaOp<Square<U3>>, U9> = ();
+        let _: AssertSameOp<AreaOp<Rect<U2, U5>>, U10> = ();
+    }
+}
+
 #[test]
 fn compile_fail_test() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/macro/fail_match_attribute.rs");
+    t.compile_fail("tests/macro/fail_match_capture.rs");
 }
@@ -24,3 +24,66 @@ fn test() {
     let _: AssertSameOp<GetOneIfIsSquareOfOp<U49, U7>, U1> = ();
     let _: AssertSameOp<GetOneIfIsSquareOfOp<U5, U2>, U0> = ();
 }
+
+mod import_test {
+    use crate::common::*;
+    use typenum::consts::*;
+
+    pub mod ops {
+        use core::ops::Add;
+        use typenum::Sum;
+
+        pub trait Twice {
+            type Output;
+        }
+
+        impl<N: Add<N>> Twice for N {
+            type Output = Sum<N, N>;
+        }
+    }
+
+    typ! {
+        use self::ops::Twice;
+        use typenum::Min;
+
+        fn Quadruple<n>(n: Unsigned) -> Unsigned {
+            let twice = n.Twice();
+            twice.Twice()
+        }
+
+        fn Smaller<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+            lhs.Min(rhs)
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<QuadrupleOp<U3>, U12> = ();
+        let _: AssertSameOp<SmallerOp<U3, U7>, U3> = ();
+    }
+}
+
+mod shadow_test {
+    use crate::common::*;
+    use typenum::consts::*;
+
+    // shadows the typenum trait imported by the glob import
+    pub trait Max<Rhs> {
+        type Output;
+    }
+
+    impl<Lhs, Rhs> Max<Rhs> for Lhs {
+        type Output = U100;
+    }
+
+    typ! {
+        fn Larger<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+            lhs.Max(rhs)
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<LargerOp<U3, U7>, U100> = ();
+    }
+}
@@ -0,0 +1,92 @@
+use crate::common::*;
+use typenum::consts::*;
+
+typ! {
+    pub mod list {
+        use super::*;
+
+        pub enum List {
+            Cons(_, List),
+            Nil,
+        }
+
+        pub fn Append<list, value>(list: List, value: _) -> List {
+            match list {
+                #[generics(head, tail: List)]
+                Cons::<head, tail> => {
+                    let new_tail = self::Append(tail, value);
+                    Cons::<head, new_tail>
+                }
+                Nil => Cons::<value, Nil>,
+            }
+        }
+
+        pub mod len {
+            use super::*;
+
+            pub fn Len<list>(list: super::List) -> Unsigned {
+                match list {
+                    #[generics(head, tail: super::List)]
+                    super::Cons::<head, tail> => Len(tail) + 1u,
+                    super::Nil => 0u,
+                }
+            }
+        }
+    }
+
+    fn Push<input, value>(input: list::List, value: _) -> list::List {
+        list::Append(input, value)
+    }
+
+    fn LenAfterPush<input, value>(input: list::List, value: _) -> Unsigned {
+        let pushed: list::List = self::Push(input, value);
+        list::len::Len(pushed)
+    }
+}
+
+#[test]
+fn test() {
+    use list::{Cons, Nil};
+
+    let _: AssertSameOp<list::AppendOp<Nil, B1>, Cons<B1, Nil>> = ();
+    let _: AssertSameOp<list::len::LenOp<Cons<B0, Nil>>, U1> = ();
+    let _: AssertSameOp<PushOp<Cons<B0, Nil>, B1>, Cons<B0, Cons<B1, Nil>>> = ();
+    let _: AssertSameOp<LenAfterPushOp<Cons<B0, Nil>, B0>, U2> = ();
+}
+
+mod outer_scope_test {
+    use crate::common::*;
+    use typenum::consts::*;
+
+    typ! {
+        pub struct Point {
+            pub x: Unsigned,
+            pub y: Unsigned,
+        }
+
+        impl<x: Unsigned, y: Unsigned> Point<x, y> {
+            fn Area(self) -> Unsigned {
+                x * y
+            }
+        }
+
+        pub mod geometry {
+            use super::*;
+
+            pub fn Perimeter<p>(p: PointMarker) -> Unsigned {
+                (p.x + p.y) * 2u
+            }
+
+            pub fn AreaPlusPerimeter<p>(p: PointMarker) -> Unsigned {
+                let area: Unsigned = p.Area();
+                area + Perimeter(p)
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<geometry::PerimeterOp<Point<U2, U3>>, U10> = ();
+        let _: AssertSameOp<geometry::AreaPlusPerimeterOp<Point<U2, U3>>, U16> = ();
+    }
+}
@@ -0,0 +1,58 @@
+use crate::common::*;
+use typenum::consts::*;
+
+typ! {
+    fn Gcd<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+        if rhs == 0u {
+            return lhs;
+        }
+        let rem: Unsigned = lhs % rhs;
+        Gcd(rhs, rem)
+    }
+
+    fn Digits<n>(n: Unsigned) -> Unsigned {
+        if n == 0u {
+            return 0u;
+        }
+        if n < 10u {
+            return 1u;
+        }
+        2u
+    }
+
+    fn Sign<n>(n: Integer) -> Integer {
+        if n > 0 {
+            return 1;
+        } else if n < 0 {
+            return -1;
+        }
+        0
+    }
+
+    fn Clamp<n>(n: Unsigned) -> Unsigned {
+        let mut value = n;
+        if value > 5u {
+            value = 5u;
+        } else {
+            return value;
+        }
+        value + 10u
+    }
+}
+
+#[test]
+fn test() {
+    let _: AssertSameOp<GcdOp<U12, U18>, U6> = ();
+    let _: AssertSameOp<GcdOp<U7, U0>, U7> = ();
+
+    let _: AssertSameOp<DigitsOp<U0>, U0> = ();
+    let _: AssertSameOp<DigitsOp<U7>, U1> = ();
+    let _: AssertSameOp<DigitsOp<U42>, U2> = ();
+
+    let _: AssertSameOp<SignOp<P3>, P1> = ();
+    let _: AssertSameOp<SignOp<N3>, N1> = ();
+    let _: AssertSameOp<SignOp<Z0>, Z0> = ();
+
+    let _: AssertSameOp<ClampOp<U3>, U3> = ();
+    let _: AssertSameOp<ClampOp<U8>, U15> = ();
+}
@@ -0,0 +1,102 @@
+use crate::common::*;
+use std::marker::PhantomData;
+use typenum::consts::*;
+
+typ! {
+    pub struct Point {
+        pub x: Integer,
+        pub y: Integer,
+    }
+
+    pub struct Pair(pub Unsigned, pub Unsigned);
+
+    pub struct Origin;
+
+    fn MakePoint<x, y>(x: Integer, y: Integer) -> PointMarker {
+        Point::<x, y>
+    }
+
+    fn NewPoint<x, y>(x: Integer, y: Integer) -> PointMarker {
+        Point { y: y, x: x }
+    }
+
+    fn Transpose<p>(p: PointMarker) -> PointMarker {
+        let x = p.y;
+        let y = p.x;
+        Point { x, y }
+    }
+
+    fn NewPair<a, b>(a: Unsigned, b: Unsigned) -> PairMarker {
+        Pair { 1: b + 1u, 0: a }
+    }
+
+    fn SumXY<p>(p: PointMarker) -> Integer {
+        p.x + p.y
+    }
+
+    fn SumPair<p>(p: PairMarker) -> Unsigned {
+        let first = p.0;
+        first + p.1
+    }
+}
+
+mod ambiguous_field {
+    use super::*;
+
+    typ! {
+        pub struct Vec2 { x: Unsigned, y: Unsigned }
+        pub struct Vec3 { x: Unsigned, y: Unsigned, z: Unsigned }
+
+        fn GetX<v>(v: Vec3Marker) -> Unsigned {
+            v.x
+        }
+
+        fn GetZ<v>(v: _) -> Unsigned {
+            v.z
+        }
+
+        fn Flatten<x, y>(x: Unsigned, y: Unsigned) -> Unsigned {
+            let v = Vec2::<x, y>;
+            v.x * 10u + v.y
+        }
+    }
+
+    #[test]
+    fn test() {
+        let _: AssertSameOp<GetXOp<Vec3<U1, U2, U3>>, U1> = ();
+        let _: AssertSameOp<GetZOp<Vec3<U1, U2, U3>>, U3> = ();
+        let _: AssertSameOp<FlattenOp<U4, U2>, U42> = ();
+    }
+}
+
+fn assert_point<T: PointMarker>() {}
+fn assert_pair<T: PairMarker>() {}
+fn assert_origin<T: OriginMarker>() {}
+
+#[test]
+fn test() {
+    let _: Point<P1, N2> = Point {
+        x: PhantomData,
+        y: PhantomData,
+    };
+    let _: Pair<U1, U2> = Pair(PhantomData, PhantomData);
+    let _: Origin = Origin;
+
+    assert_point::<Point<Z0, P3>>();
+    assert_pair::<Pair<U0, U3>>();
+    assert_origin::<Origin>();
+
+    let _: AssertSameOp<MakePointOp<P1, N2>, Point<P1, N2>> = ();
+    let _: AssertSameOp<NewPointOp<P1, N2>, Point<P1, N2>> = ();
+    let _: AssertSameOp<TransposeOp<Point<P1, N2>>, Point<N2, P1>> = ();
+    let _: AssertSameOp<NewPairOp<U1, U2>, Pair<U1, U3>> = ();
+    let _: AssertSameOp<SumXYOp<Point<P1, N3>>, N2> = ();
+    let _: AssertSameOp<SumPairOp<Pair<U1, U2>>, U3> = ();
+    assert_eq!(std::mem::size_of::<Point<P1, N2>>(), 0);
+}
+
+#[test]
+fn compile_fail_test() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/macro/fail_struct_expr.rs");
+}
@@ -0,0 +1,109 @@
+use crate::common::*;
+use typenum::consts::*;
+
+typ! {
+    pub enum List {
+        Cons(_, List),
+        Nil,
+    }
+
+    pub struct Repeat {
+        value: _,
+        count: Unsigned,
+    }
+
+    pub trait Container {
+        fn Len(self) -> Unsigned;
+        fn Get<index>(self, index: Unsigned) -> _;
+    }
+
+    impl Container for Nil {
+        fn Len(self) -> Unsigned {
+            0u
+        }
+
+        fn Get<index>(self, index: Unsigned) -> _ {
+            ()
+        }
+    }
+
+    impl<head, tail: List + Container> Container for Cons<head, tail> {
+        fn Len(self) -> Unsigned {
+            tail.Len() + 1u
+        }
+
+        fn Get<index>(self, index: Unsigned) -> _ {
+            if index == 0u {
+                head
+            } else {
+                let prev: Unsigned = index - 1u;
+                tail.Get(prev)
+            }
+        }
+    }
+
+    impl<value, count: Unsigned> Container for Repeat<value, count> {
+        fn Len(self) -> Unsigned {
+            count
+        }
+
+        fn Get<index>(self, index: Unsigned) -> _ {
+            value
+        }
+    }
+
+    pub trait Indexed {
+        fn Pick<index>(self) -> _;
+    }
+
+    impl<head, tail: List + Container> Indexed for Cons<head, tail> {
+        fn Pick<index>(self) -> _
+        where
+            index: Unsigned,
+        {
+            self.Get(index)
+        }
+    }
+
+    impl<value, count: Unsigned> Indexed for Repeat<value, count> {
+        fn Pick<index>(self) -> _ {
+            value
+        }
+    }
+
+    fn Second<list>(list: Indexed) {
+        list.Pick::<U1>()
+    }
+}
+
+fn assert_container<T: Container>() {}
+
+#[test]
+fn test() {
+    type Pair = Cons<B1, Cons<U3, Nil>>;
+
+    assert_container::<Nil>();
+    assert_container::<Pair>();
+    assert_container::<Repeat<B0, U4>>();
+
+    let _: AssertSameOp<LenOp<Nil>, U0> = ();
+    let _: AssertSameOp<LenOp<Pair>, U2> = ();
+    let _: AssertSameOp<LenOp<Repeat<B0, U4>>, U4> = ();
+
+    let _: AssertSameOp<GetOp<Pair, U0>, B1> = ();
+    let _: AssertSameOp<GetOp<Pair, U1>, U3> = ();
+    let _: AssertSameOp<GetOp<Repeat<B0, U4>, U2>, B0> = ();
+
+    let _: AssertSameOp<PickOp<Pair, U0>, B1> = ();
+    let _: AssertSameOp<PickOp<Repeat<B0, U4>, U3>, B0> = ();
+
+    let _: AssertSameOp<SecondOp<Pair>, U3> = ();
+    let _: AssertSameOp<SecondOp<Repeat<B0, U4>>, B0> = ();
+}
+
+#[test]
+fn compile_fail_test() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/macro/fail_method_call.rs");
+    t.compile_fail("tests/macro/fail_impl_missing.rs");
+}
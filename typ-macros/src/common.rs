@@ -0,0 +1,46 @@
+pub use by_address::ByAddress;
+pub use indexmap::{IndexMap, IndexSet};
+pub use itertools::Itertools;
+pub use proc_macro2::{Span, TokenStream};
+pub use quote::{format_ident, quote, quote_spanned, ToTokens};
+pub use radix_trie::{Trie, TrieCommon, TrieKey};
+pub use std::{
+    borrow::{Borrow, BorrowMut, Cow},
+    cell::{Cell, Ref, RefCell, RefMut},
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    convert::{TryFrom, TryInto},
+    fmt::Debug,
+    hash::Hash,
+    hash::Hasher,
+    iter::{self, Extend, FromIterator, Sum},
+    mem,
+    ops::{Add, AddAssign, Deref, DerefMut},
+    rc::{Rc, Weak},
+};
+pub use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token,
+    visit_mut::VisitMut,
+    Arm, AttrStyle, Attribute, BinOp, Block, ConstParam, Constraint, Error, Expr, ExprAssign,
+    ExprBinary, ExprBlock, ExprBreak, ExprCall, ExprClosure, ExprContinue, ExprField, ExprForLoop,
+    ExprIf, ExprIndex, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch, ExprMethodCall, ExprPath,
+    ExprRange, ExprReturn, ExprStruct, ExprTuple, ExprUnary, ExprWhile, Field, FieldPat,
+    FieldValue, Fields, FnArg, GenericArgument, GenericMethodArgument, GenericParam, Generics,
+    Ident, ImplItem, ImplItemMethod, ImplItemType, Item, ItemConst, ItemEnum, ItemFn, ItemImpl,
+    ItemMod, ItemStruct, ItemTrait, ItemType, ItemUse, Lifetime, LifetimeDef, Lit, LitBool, LitInt,
+    Local, Macro, Member, Pat, PatIdent, PatLit, PatOr, PatPath, PatStruct, PatTuple,
+    PatTupleStruct, PatType, Path, PathArguments, PathSegment, PredicateType, QSelf, RangeLimits,
+    Receiver, ReturnType, Signature, Stmt, Token, TraitBound, TraitBoundModifier, TraitItem,
+    TraitItemMethod, Type, TypeParam, TypeParamBound, TypeParen, TypePath, TypeTraitObject,
+    TypeTuple, UnOp, UseGroup, UseName, UsePath, UseRename, UseTree, Variant, Visibility,
+    WherePredicate,
+};
+
+pub const IDENT_PREFIX: &str = "__TYP_";
+
+// the most arguments of the OperatorN traits declared in the typ crate
+pub const MAX_OPERATOR_ARITY: usize = 4;
@@ -1,5 +1,6 @@
 use crate::{
     common::*,
+    registry::Registry,
     utils::{IntoRc, Shared, SharedCell},
     var::{PredicateTypeVar, TypeParamBoundVar, TypeVar, WherePredicateVar},
 };
@@ -12,20 +13,24 @@ mod env {
     #[derive(Debug)]
     pub struct Env {
         self_name: Rc<Ident>,
+        registry: Rc<Registry>,
         variables: IndexSet<Shared<Variable>>,
         type_predicates: HashMap<TypeVar, HashSet<TypeParamBoundVar>>,
         namespace: Vec<HashMap<Rc<Ident>, Shared<Variable>>>,
         trait_name_prefixes: SharedCell<Trie<String, usize>>,
+        closures: SharedCell<HashMap<Ident, Closure>>,
     }
 
     impl Env {
-        pub fn new(self_name: Ident) -> Self {
+        pub fn new(self_name: Ident, registry: Rc<Registry>) -> Self {
             Self {
                 self_name: Rc::new(self_name),
+                registry,
                 variables: IndexSet::new(),
                 type_predicates: HashMap::new(),
                 namespace: vec![HashMap::new()],
                 trait_name_prefixes: SharedCell::new(Trie::new()),
+                closures: SharedCell::new(HashMap::new()),
             }
         }
 
@@ -37,22 +42,30 @@ mod env {
                 .find_map(|variables| variables.get(ident).cloned())
         }
 
+        pub fn registry(&self) -> &Registry {
+            &self.registry
+        }
+
         pub fn branch(&self) -> Self {
             let self_name = self.self_name.clone();
+            let registry = self.registry.clone();
             let variables = self.variables.clone();
             let type_predicates = self.type_predicates.clone();
             let mut namespace = self.namespace.clone();
             let trait_name_prefixes = self.trait_name_prefixes.clone();
+            let closures = self.closures.clone();
 
             // add one extra scope
             namespace.push(HashMap::new());
 
             Self {
                 self_name,
+                registry,
                 variables,
                 type_predicates,
                 namespace,
                 trait_name_prefixes,
+                closures,
             }
         }
 
@@ -180,6 +193,13 @@ mod env {
                 .collect()
         }
 
+        pub fn trait_bounds(&self, ty: &TypeVar) -> Vec<TypeParamBoundVar> {
+            self.type_predicates
+                .get(ty)
+                .map(|bounds| bounds.iter().cloned().collect())
+                .unwrap_or_default()
+        }
+
         pub fn predicates(&self) -> Vec<WherePredicateVar> {
             let type_predicates = self.type_predicates.iter().map(|(bounded_ty, bounds)| {
                 let bounds: Vec<_> = bounds.iter().cloned().collect();
@@ -223,6 +243,21 @@ mod env {
 
             Some(format_ident!("{}{}", prefix, count))
         }
+
+        pub fn insert_closure(&mut self, marker: Ident, closure: Closure) {
+            self.closures.borrow_mut().insert(marker, closure);
+        }
+
+        pub fn closure(&self, marker: &Ident) -> Option<Closure> {
+            self.closures.borrow().get(marker).cloned()
+        }
+    }
+
+    // the closure trait implemented by the marker type of a closure
+    #[derive(Debug, Clone)]
+    pub struct Closure {
+        pub trait_name: Ident,
+        pub arity: usize,
     }
 
     #[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
@@ -0,0 +1,35 @@
+//! The procedural macros of the [typ](https://docs.rs/typ/) crate, which re-exports them.
+
+#![feature(hash_set_entry)]
+
+mod common;
+mod env;
+mod parse;
+mod registry;
+mod trans;
+mod tyint;
+mod utils;
+mod var;
+
+use crate::{common::*, parse::ItemVec};
+
+/// The main macro that translates the TYP langauge to actual Rust implementations.
+#[proc_macro]
+pub fn typ(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ItemVec(items) = parse_macro_input!(tokens as ItemVec);
+    crate::trans::translate_items(&items, None)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Constructs a signed integer type from an integer literal.
+#[proc_macro]
+pub fn tyint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    tyint::tyint(input)
+}
+
+/// Constructs an unsigned integer type from an integer literal.
+#[proc_macro]
+pub fn tyuint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    tyint::tyuint(input)
+}
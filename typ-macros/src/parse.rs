@@ -0,0 +1,226 @@
+use crate::{
+    common::*,
+    env::Env,
+    var::{
+        ParseTypeParamBoundVar, ParseTypeVar, ParseWherePredicateVar, PredicateTypeVar,
+        WherePredicateVar,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct ItemVec(pub Vec<Item>);
+
+impl Parse for ItemVec {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut items = Vec::new();
+        while !input.cursor().eof() {
+            items.push(parse_item(input)?);
+        }
+        Ok(ItemVec(items))
+    }
+}
+
+fn parse_item(input: ParseStream) -> syn::Result<Item> {
+    // look ahead the item kind after attributes and visibility
+    let fork = input.fork();
+    fork.call(Attribute::parse_outer)?;
+    fork.parse::<Visibility>()?;
+
+    if fork.peek(Token![type]) {
+        // an ordinary type alias is kept as it is
+        if let Ok(item) = input.fork().parse::<ItemType>() {
+            // "A + B" is a value rather than a trait object
+            if !matches!(
+                &*item.ty,
+                Type::TraitObject(TypeTraitObject {
+                    dyn_token: None,
+                    ..
+                })
+            ) {
+                return Ok(Item::Type(input.parse()?));
+            }
+        }
+
+        // otherwise the value of type alias is an expression, which is not understood by syn
+        let item: ItemTypeExpr = input.parse()?;
+        Ok(Item::Verbatim(item.into_token_stream()))
+    } else if fork.peek(Token![mod]) && fork.peek3(token::Brace) {
+        // parse module content recursively
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let mod_token = input.parse()?;
+        let ident = input.parse()?;
+        let content;
+        let brace = syn::braced!(content in input);
+        let ItemVec(items) = content.parse()?;
+
+        Ok(Item::Mod(ItemMod {
+            attrs,
+            vis,
+            mod_token,
+            ident,
+            content: Some((brace, items)),
+            semi: None,
+        }))
+    } else {
+        input.parse()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemTypeExpr {
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub type_token: Token![type],
+    pub ident: Ident,
+    pub generics: Generics,
+    pub eq_token: Token![=],
+    pub expr: Expr,
+    pub semi_token: Token![;],
+}
+
+impl Parse for ItemTypeExpr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            attrs: input.call(Attribute::parse_outer)?,
+            vis: input.parse()?,
+            type_token: input.parse()?,
+            ident: input.parse()?,
+            generics: input.parse()?,
+            eq_token: input.parse()?,
+            expr: input.parse()?,
+            semi_token: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for ItemTypeExpr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            attrs,
+            vis,
+            type_token,
+            ident,
+            generics,
+            eq_token,
+            expr,
+            semi_token,
+        } = self;
+
+        tokens.extend(quote! {
+            #(#attrs)*
+            #vis #type_token #ident #generics #eq_token #expr #semi_token
+        });
+    }
+}
+
+// let-else statement, which syn parses into a verbatim expression
+#[derive(Debug, Clone)]
+pub struct LocalElse {
+    pub let_token: Token![let],
+    pub pat: Pat,
+    pub eq_token: Token![=],
+    pub expr: Expr,
+    pub else_token: Token![else],
+    pub else_block: Block,
+}
+
+impl Parse for LocalElse {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            let_token: input.parse()?,
+            pat: input.parse()?,
+            eq_token: input.parse()?,
+            expr: input.parse()?,
+            else_token: input.parse()?,
+            else_block: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for LocalElse {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            let_token,
+            pat,
+            eq_token,
+            expr,
+            else_token,
+            else_block,
+        } = self;
+
+        tokens.extend(quote! {
+            #let_token #pat #eq_token #expr #else_token #else_block
+        });
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SimpleTypeParam {
+    pub ident: Ident,
+    pub bounds: Punctuated<TraitBound, syn::token::Add>,
+}
+
+impl Parse for SimpleTypeParam {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        let param = if input.peek(syn::token::Colon) {
+            let _: syn::token::Colon = input.parse()?;
+            let bounds = Punctuated::parse_separated_nonempty(input)?;
+            Self { ident, bounds }
+        } else {
+            Self {
+                ident,
+                bounds: Punctuated::new(),
+            }
+        };
+
+        Ok(param)
+    }
+}
+
+impl ParseWherePredicateVar for SimpleTypeParam {
+    fn parse_where_predicate_var(&self, env: &mut Env) -> syn::Result<WherePredicateVar> {
+        let SimpleTypeParam { ident, bounds } = self;
+
+        let bounded_ty = ident.parse_type_var(env)?;
+        let bounds = bounds
+            .iter()
+            .map(|bound| bound.parse_type_param_bound_var(env))
+            .try_collect()?;
+
+        Ok(WherePredicateVar::Type(PredicateTypeVar {
+            bounded_ty,
+            bounds,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GenericsAttr {
+    pub params: Punctuated<SimpleTypeParam, syn::token::Comma>,
+}
+
+impl Parse for GenericsAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let params = Punctuated::parse_terminated(&content)?;
+        Ok(Self { params })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CaptureAttr {
+    pub params: Punctuated<SimpleTypeParam, syn::token::Comma>,
+}
+
+impl Parse for CaptureAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let params = Punctuated::parse_terminated(&content)?;
+        Ok(Self { params })
+    }
+}
@@ -0,0 +1,338 @@
+use crate::common::*;
+
+#[derive(Debug, Default)]
+pub struct Registry {
+    // the registry of the enclosing module, which is looked up if nothing is found here
+    parent: Option<Rc<Registry>>,
+    records: Vec<RecordDecl>,
+    traits: Vec<TraitDecl>,
+    operators: Vec<OperatorDecl>,
+    // the names brought in by use items, where a glob import may bring in any name
+    imports: Vec<Ident>,
+    has_glob_import: bool,
+}
+
+impl Registry {
+    pub fn new(items: &[Item], parent: Option<Rc<Registry>>) -> Self {
+        let mut registry = Self {
+            parent,
+            ..Default::default()
+        };
+
+        for item in items.iter() {
+            match item {
+                Item::Struct(ItemStruct { ident, fields, .. }) => {
+                    registry.records.push(RecordDecl::new(ident, None, fields));
+                }
+                Item::Enum(ItemEnum {
+                    ident: enum_ident,
+                    variants,
+                    ..
+                }) => {
+                    let records = variants.iter().map(|Variant { ident, fields, .. }| {
+                        RecordDecl::new(ident, Some(enum_ident), fields)
+                    });
+                    registry.records.extend(records);
+                }
+                Item::Fn(ItemFn { sig, .. }) => {
+                    registry
+                        .operators
+                        .push(OperatorDecl::new(sig, Path::from(sig.ident.to_owned())));
+                }
+                Item::Impl(ItemImpl { trait_, items, .. }) => {
+                    let operators = items.iter().filter_map(|item| match item {
+                        ImplItem::Method(ImplItemMethod { sig, .. }) => {
+                            // the method trait is declared next to the impl trait
+                            let trait_path = match trait_ {
+                                Some((_, path, _)) => {
+                                    let mut path = path.to_owned();
+                                    let last = path.segments.last_mut().unwrap();
+                                    last.ident = sig.ident.to_owned();
+                                    last.arguments = PathArguments::None;
+                                    path
+                                }
+                                None => Path::from(sig.ident.to_owned()),
+                            };
+                            Some(OperatorDecl::new(sig, trait_path))
+                        }
+                        _ => None,
+                    });
+                    registry.operators.extend(operators);
+                }
+                Item::Trait(ItemTrait { ident, items, .. }) => {
+                    registry.traits.push(TraitDecl::new(ident, items));
+                    let operators = items.iter().filter_map(|item| match item {
+                        TraitItem::Method(TraitItemMethod { sig, .. }) => {
+                            Some(OperatorDecl::new(sig, Path::from(sig.ident.to_owned())))
+                        }
+                        _ => None,
+                    });
+                    registry.operators.extend(operators);
+                }
+                Item::Use(ItemUse { tree, .. }) => registry.insert_use_tree(tree),
+                _ => (),
+            }
+        }
+
+        registry
+    }
+
+    fn insert_use_tree(&mut self, tree: &UseTree) {
+        match tree {
+            UseTree::Path(UsePath { tree, .. }) => self.insert_use_tree(tree),
+            UseTree::Name(UseName { ident }) => {
+                if ident != "self" {
+                    self.imports.push(ident.to_owned());
+                }
+            }
+            UseTree::Rename(UseRename { rename, .. }) => self.imports.push(rename.to_owned()),
+            UseTree::Glob(_) => self.has_glob_import = true,
+            UseTree::Group(UseGroup { items, .. }) => {
+                items.iter().for_each(|tree| self.insert_use_tree(tree))
+            }
+        }
+    }
+
+    pub fn record(&self, ident: &Ident) -> Option<&RecordDecl> {
+        self.records
+            .iter()
+            .find(|record| &record.ident == ident)
+            .or_else(|| self.parent.as_ref()?.record(ident))
+    }
+
+    // find the trait declared in a typ! block, where the path may start with self or super
+    pub fn trait_decl(&self, path: &Path) -> Option<&TraitDecl> {
+        if path.leading_colon.is_some() {
+            return None;
+        }
+
+        let mut registry = self;
+        let mut is_relative = false;
+        let mut segments = path.segments.iter().peekable();
+        while let Some(segment) = segments.peek() {
+            if segment.ident == "self" {
+                is_relative = true;
+            } else if segment.ident == "super" {
+                is_relative = true;
+                registry = registry.parent.as_deref()?;
+            } else {
+                break;
+            }
+            segments.next();
+        }
+
+        let ident = match segments.collect::<Vec<_>>().as_slice() {
+            [segment] => &segment.ident,
+            _ => return None,
+        };
+        let trait_decl = registry.traits.iter().find(|trait_| &trait_.ident == ident);
+        match &registry.parent {
+            Some(parent) if trait_decl.is_none() && !is_relative => {
+                parent.trait_decl(&Path::from(ident.to_owned()))
+            }
+            _ => trait_decl,
+        }
+    }
+
+    pub fn variants(&self, enum_ident: &Ident) -> Vec<&RecordDecl> {
+        let variants = self.local_variants(enum_ident);
+        match &self.parent {
+            Some(parent) if variants.is_empty() => parent.variants(enum_ident),
+            _ => variants,
+        }
+    }
+
+    // the constructors of an enum shaped like enum List { Cons(_, List), Nil }
+    pub fn list_variants(&self, enum_ident: &Ident) -> Option<(&RecordDecl, &RecordDecl)> {
+        let (cons, nil) = match self.variants(enum_ident).as_slice() {
+            [cons, nil] | [nil, cons] if nil.fields.is_empty() => (*cons, *nil),
+            _ => return None,
+        };
+        let is_cons = matches!(
+            cons.field_types.as_slice(),
+            [_, Type::Path(TypePath { qself: None, path })] if path.is_ident(enum_ident)
+        );
+        if is_cons {
+            Some((cons, nil))
+        } else {
+            None
+        }
+    }
+
+    pub fn operators(&self, ident: &Ident) -> Vec<OperatorDecl> {
+        let operators: Vec<_> = self
+            .operators
+            .iter()
+            .filter(|operator| &operator.ident == ident)
+            .cloned()
+            .collect();
+
+        match &self.parent {
+            // the trait paths of the enclosing module are relative to it
+            Some(parent) if operators.is_empty() => parent
+                .operators(ident)
+                .into_iter()
+                .map(|operator| OperatorDecl {
+                    trait_path: rebase_on_parent(operator.trait_path),
+                    ..operator
+                })
+                .collect(),
+            _ => operators,
+        }
+    }
+
+    pub fn import(&self, ident: &Ident) -> Option<Path> {
+        if self.has_glob_import || self.imports.contains(ident) {
+            return Some(Path::from(ident.to_owned()));
+        }
+
+        // the imports of the enclosing module are not glob imported to child modules
+        let path = self.parent.as_ref()?.import(ident)?;
+        Some(rebase_on_parent(path))
+    }
+
+    pub fn records_with_field(&self, member: &Member) -> Vec<&RecordDecl> {
+        let records: Vec<_> = self
+            .records
+            .iter()
+            .filter(|record| record.fields.contains(member))
+            .collect();
+
+        match &self.parent {
+            Some(parent) if records.is_empty() => parent.records_with_field(member),
+            _ => records,
+        }
+    }
+
+    fn local_variants(&self, enum_ident: &Ident) -> Vec<&RecordDecl> {
+        self.records
+            .iter()
+            .filter(|record| record.enum_ident.as_ref() == Some(enum_ident))
+            .collect()
+    }
+}
+
+// make a path relative to the enclosing module valid in the child module
+fn rebase_on_parent(mut path: Path) -> Path {
+    if path.leading_colon.is_some() {
+        return path;
+    }
+
+    let first = &path.segments[0].ident;
+    let super_ = Ident::new("super", first.span());
+    if first == "self" {
+        path.segments[0].ident = super_;
+    } else if first != "crate" {
+        path.segments.insert(0, PathSegment::from(super_));
+    }
+    path
+}
+
+#[derive(Debug)]
+pub struct RecordDecl {
+    pub ident: Ident,
+    pub enum_ident: Option<Ident>,
+    pub fields: Vec<Member>,
+    pub field_types: Vec<Type>,
+}
+
+impl RecordDecl {
+    fn new(ident: &Ident, enum_ident: Option<&Ident>, fields: &Fields) -> Self {
+        let members = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| match &field.ident {
+                Some(ident) => Member::Named(ident.to_owned()),
+                None => Member::Unnamed(index.into()),
+            })
+            .collect();
+        let field_types = fields.iter().map(|field| field.ty.to_owned()).collect();
+
+        Self {
+            ident: ident.to_owned(),
+            enum_ident: enum_ident.cloned(),
+            fields: members,
+            field_types,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TraitDecl {
+    pub ident: Ident,
+    pub methods: Vec<Ident>,
+}
+
+impl TraitDecl {
+    fn new(ident: &Ident, items: &[TraitItem]) -> Self {
+        let methods = items
+            .iter()
+            .filter_map(|item| match item {
+                TraitItem::Method(TraitItemMethod { sig, .. }) => Some(sig.ident.to_owned()),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            ident: ident.to_owned(),
+            methods,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OperatorDecl {
+    pub ident: Ident,
+    pub trait_path: Path,
+    pub has_receiver: bool,
+    pub num_explicit_generics: usize,
+    pub num_args: usize,
+}
+
+impl OperatorDecl {
+    fn new(sig: &Signature, trait_path: Path) -> Self {
+        let has_receiver = matches!(sig.inputs.first(), Some(FnArg::Receiver(_)));
+        let num_args = sig
+            .inputs
+            .iter()
+            .filter(|input| matches!(input, FnArg::Typed(_)))
+            .count();
+
+        Self {
+            ident: sig.ident.to_owned(),
+            trait_path,
+            has_receiver,
+            num_explicit_generics: explicit_generics(sig).len(),
+            num_args,
+        }
+    }
+}
+
+// the generics not bound by any argument pattern, which are passed by turbofish
+pub fn explicit_generics(sig: &Signature) -> Vec<Ident> {
+    struct IdentCollector(HashSet<Ident>);
+
+    impl VisitMut for IdentCollector {
+        fn visit_ident_mut(&mut self, ident: &mut Ident) {
+            self.0.insert(ident.to_owned());
+        }
+    }
+
+    let mut collector = IdentCollector(HashSet::new());
+    for input in sig.inputs.iter() {
+        if let FnArg::Typed(PatType { pat, .. }) = input {
+            collector.visit_pat_mut(&mut pat.as_ref().to_owned());
+        }
+    }
+
+    sig.generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(TypeParam { ident, .. }) if !collector.0.contains(ident) => {
+                Some(ident.to_owned())
+            }
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,107 @@
+use super::*;
+
+pub fn translate_type_alias(
+    item: &ItemTypeExpr,
+    registry: &Rc<Registry>,
+) -> syn::Result<TokenStream> {
+    let ItemTypeExpr {
+        attrs,
+        vis,
+        ident,
+        generics,
+        expr,
+        ..
+    } = item;
+
+    // sanity check
+    if let Some(param) = generics.params.first() {
+        return Err(Error::new(
+            param.span(),
+            "generics on type alias are not supported",
+        ));
+    }
+    if let Some(where_clause) = &generics.where_clause {
+        return Err(Error::new(
+            where_clause.span(),
+            "where clause on type alias is not supported",
+        ));
+    }
+
+    translate_alias(attrs, vis, ident, expr, None, registry)
+}
+
+pub fn translate_const(const_: &ItemConst, registry: &Rc<Registry>) -> syn::Result<TokenStream> {
+    let ItemConst {
+        attrs,
+        vis,
+        ident,
+        ty,
+        expr,
+        ..
+    } = const_;
+
+    translate_alias(attrs, vis, ident, expr, Some(ty), registry)
+}
+
+fn translate_alias(
+    attrs: &[Attribute],
+    vis: &Visibility,
+    ident: &Ident,
+    expr: &Expr,
+    ty: Option<&Type>,
+    registry: &Rc<Registry>,
+) -> syn::Result<TokenStream> {
+    let mut env = Env::new(ident.clone(), registry.clone());
+
+    // evaluate the value
+    let mut items = vec![];
+    let output = translate_expr(expr, &mut env, &mut items)?;
+
+    // insert trait bounds on the value
+    if let Some(ty) = ty {
+        let bounds = ty.parse_type_param_bounds_var(&mut env)?;
+        if !bounds.is_empty() {
+            env.insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
+                bounded_ty: output.clone(),
+                bounds,
+            }));
+        }
+    }
+
+    // the value has no free quantifiers
+    let subsitution = IndexMap::new();
+    let output = output.substitute(&env, &subsitution);
+    let predicates: Vec<_> = env
+        .predicates()
+        .into_iter()
+        .map(|predicate| predicate.substitute(&env, &subsitution))
+        .collect();
+
+    let mod_name = format_ident!("{}mod_{}", IDENT_PREFIX, ident);
+    let check_name = format_ident!("{}Check", IDENT_PREFIX);
+
+    let expanded = quote! {
+        #vis use #mod_name::#ident;
+
+        #[allow(non_snake_case)]
+        mod #mod_name {
+            use super::*;
+
+            #(#attrs)*
+            #[allow(non_camel_case_types)]
+            pub type #ident = #output;
+
+            // fails to compile if the value does not satisfy the predicates
+            #[allow(dead_code)]
+            trait #check_name {}
+            impl #check_name for ()
+            where
+                #(#predicates),*
+            {}
+
+            #(#items)*
+        }
+    };
+
+    Ok(expanded)
+}
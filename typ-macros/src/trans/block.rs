@@ -11,8 +11,32 @@ where
 
     // creates a subscope
     scope.sub_scope(|scope| {
-        for stmt in block.stmts.iter() {
+        for (index, stmt) in block.stmts.iter().enumerate() {
             match stmt {
+                Stmt::Semi(Expr::Verbatim(tokens), _semi) => {
+                    // the rest of the block becomes the matching arm of let-else
+                    let LocalElse {
+                        pat,
+                        expr,
+                        else_block,
+                        ..
+                    } = syn::parse2(tokens.to_owned())?;
+                    let rest = &block.stmts[(index + 1)..];
+                    let match_: ExprMatch = syn::parse2(quote! {
+                        match #expr {
+                            #pat => { #(#rest)* }
+                            _ => #else_block
+                        }
+                    })?;
+
+                    output_ty = Some(translate_lowered_match_expr(
+                        &match_,
+                        stmt.span(),
+                        scope,
+                        items,
+                    )?);
+                    break;
+                }
                 Stmt::Local(local) => {
                     // parse let statement as (let indent: trait_bounds = expr)
                     let (ident, ty_opt, is_mut, expr) = {
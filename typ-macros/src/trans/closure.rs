@@ -0,0 +1,234 @@
+use super::*;
+
+pub fn translate_closure_expr(
+    closure: &ExprClosure,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprClosure {
+        asyncness,
+        movability,
+        inputs,
+        output,
+        body,
+        ..
+    } = closure;
+
+    // sanity check
+    if let Some(async_) = asyncness {
+        return Err(Error::new(async_.span(), "the keyword is not supported"));
+    }
+    if let Some(static_) = movability {
+        return Err(Error::new(static_.span(), "the keyword is not supported"));
+    }
+
+    // generate trait and marker names
+    let trait_name = env
+        .register_trait_name(&format!("{}Closure_", IDENT_PREFIX))
+        .expect("the trait name cannot proper prefix of existing trait names");
+    let marker_name = env
+        .register_trait_name(&format!("{}ClosureFn_", IDENT_PREFIX))
+        .expect("the trait name cannot proper prefix of existing trait names");
+
+    // free quantifiers in scope are captured, and the arguments become extra free quantifiers
+    let captures = env.free_quantifiers();
+    let mut closure_env = env.branch();
+    for (index, input) in inputs.iter().enumerate() {
+        let (ident, ty) = match input {
+            Pat::Ident(PatIdent { ident, .. }) => (ident.to_owned(), None),
+            Pat::Wild(_) => (format_ident!("{}CLOSURE_ARG_{}", IDENT_PREFIX, index), None),
+            Pat::Type(PatType { pat, ty, .. }) => match &**pat {
+                Pat::Ident(PatIdent { ident, .. }) => (ident.to_owned(), Some(&**ty)),
+                Pat::Wild(_) => (
+                    format_ident!("{}CLOSURE_ARG_{}", IDENT_PREFIX, index),
+                    Some(&**ty),
+                ),
+                pat => return Err(Error::new(pat.span(), "not an identifier")),
+            },
+            pat => return Err(Error::new(pat.span(), "not an identifier")),
+        };
+        let var = closure_env.insert_free_quantifier(ident);
+
+        // insert trait bounds on the argument
+        if let Some(ty) = ty {
+            if !matches!(ty, Type::Infer(_)) {
+                let bounds = ty.parse_type_param_bounds_var(&mut closure_env)?;
+                closure_env.insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
+                    bounded_ty: TypeVar::Var(var),
+                    bounds,
+                }));
+            }
+        }
+    }
+
+    // translate the body
+    let body_value = translate_expr(body, &mut closure_env, items)?;
+    if let ReturnType::Type(_, ty) = output {
+        let bounds = ty.parse_type_param_bounds_var(&mut closure_env)?;
+        closure_env.insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
+            bounded_ty: body_value.clone(),
+            bounds,
+        }));
+    }
+
+    // generate generic names
+    let substitution: IndexMap<_, _> = closure_env
+        .free_quantifiers()
+        .into_iter()
+        .enumerate()
+        .map(|(index, var)| (var, format_ident!("{}GENERIC_{}", IDENT_PREFIX, index)))
+        .collect();
+    let generics: Vec<_> = substitution.values().collect();
+    let (capture_generics, arg_generics) = generics.split_at(captures.len());
+    let output = body_value.substitute(&closure_env, &substitution);
+    let predicates: Vec<_> = closure_env
+        .predicates()
+        .into_iter()
+        .map(|predicate| predicate.substitute(&closure_env, &substitution))
+        .collect();
+
+    // the marker type carries the captures, and implements the closure trait and Apply
+    let operator_impl = (arg_generics.len() <= MAX_OPERATOR_ARITY).then(|| {
+        let operator_trait = format_ident!("Operator{}", arg_generics.len());
+        quote! {
+            impl< #(#capture_generics),* > ::typ::#operator_trait
+                for #marker_name< #(#capture_generics),* >
+            {
+            }
+        }
+    });
+    let mut closure_items: Vec<Item> = vec![
+        syn::parse2(quote! {
+            #[allow(non_camel_case_types)]
+            pub struct #marker_name< #(#capture_generics),* >(
+                core::marker::PhantomData<( #(#capture_generics,)* )>
+            );
+        })?,
+        syn::parse2(quote! {
+            #[allow(non_snake_case, non_camel_case_types)]
+            pub trait #trait_name< #(#arg_generics),* > {
+                type Output;
+            }
+        })?,
+        syn::parse2(quote! {
+            impl< #(#generics),* > #trait_name< #(#arg_generics),* >
+                for #marker_name< #(#capture_generics),* >
+            where
+                #(#predicates),*
+            {
+                type Output = #output;
+            }
+        })?,
+        syn::parse2(quote! {
+            impl< #(#generics),* > ::typ::Apply<( #(#arg_generics,)* )>
+                for #marker_name< #(#capture_generics),* >
+            where
+                #(#predicates),*
+            {
+                type Output = #output;
+            }
+        })?,
+    ];
+    if let Some(operator_impl) = operator_impl {
+        closure_items.push(syn::parse2(operator_impl)?);
+    }
+    items.extend(closure_items);
+
+    env.insert_closure(
+        marker_name.clone(),
+        Closure {
+            trait_name,
+            arity: inputs.len(),
+        },
+    );
+
+    // the closure value is the marker type
+    Ok(TypeVar::Path(TypePathVar {
+        qself: None,
+        path: PathVar {
+            segments: vec![SegmentVar {
+                ident: marker_name,
+                arguments: PathArgumentsVar::AngleBracketed(
+                    captures.into_iter().map(TypeVar::Var).collect(),
+                ),
+            }],
+        },
+    }))
+}
+
+pub fn translate_value_call(
+    callee: &TypeVar,
+    args: Vec<TypeVar>,
+    span: Span,
+    env: &mut Env,
+) -> syn::Result<TypeVar> {
+    // look through the variables bound to the callee
+    let mut marker = callee;
+    while let TypeVar::Var(var) = marker {
+        match &var.value {
+            Some(value) => marker = value,
+            None => break,
+        }
+    }
+
+    // a known closure is called by its trait, otherwise the operator value is applied
+    let closure = match marker {
+        TypeVar::Path(TypePathVar { qself: None, path }) if path.segments.len() == 1 => {
+            env.closure(&path.segments[0].ident)
+        }
+        _ => None,
+    };
+    let trait_ = match closure {
+        Some(Closure { trait_name, arity }) => {
+            if args.len() != arity {
+                return Err(Error::new(
+                    span,
+                    count_mismatch("closure", "argument", arity, args.len()),
+                ));
+            }
+            PathVar {
+                segments: vec![SegmentVar {
+                    ident: trait_name,
+                    arguments: PathArgumentsVar::AngleBracketed(args),
+                }],
+            }
+        }
+        None => {
+            let mut trait_ = syn::parse2::<Path>(quote! { typ::Apply })
+                .unwrap()
+                .parse_pure_path(&mut vec![])
+                .unwrap();
+            trait_.segments.last_mut().unwrap().arguments =
+                PathArgumentsVar::AngleBracketed(vec![TypeVar::Tuple(TypeTupleVar {
+                    elems: args,
+                })]);
+            trait_
+        }
+    };
+
+    // construct the output type
+    let output = {
+        let mut path = trait_.clone();
+        path.segments.push(SegmentVar {
+            ident: format_ident!("Output"),
+            arguments: PathArgumentsVar::None,
+        });
+        TypeVar::Path(TypePathVar {
+            qself: Some(QSelfVar {
+                ty: Box::new(marker.clone()),
+                position: trait_.segments.len(),
+            }),
+            path,
+        })
+    };
+
+    env.insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
+        bounded_ty: marker.clone(),
+        bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
+            modifier: TraitBoundModifierVar::None,
+            path: trait_,
+        })],
+    }));
+
+    Ok(output)
+}
@@ -0,0 +1,150 @@
+use super::*;
+
+pub fn translate_curry_expr(
+    call: &ExprMethodCall,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprMethodCall { receiver, args, .. } = call;
+
+    let operator = translate_expr(receiver, env, items)?;
+    let bound_args: Vec<_> = args
+        .iter()
+        .map(|arg| translate_expr(arg, env, items))
+        .try_collect()?;
+
+    // the marker keeps the operator and the bound arguments
+    let marker_name = env
+        .register_trait_name(&format!("{}Curry_", IDENT_PREFIX))
+        .expect("the trait name cannot proper prefix of existing trait names");
+    let operator_generic = format_ident!("{}OPERATOR", IDENT_PREFIX);
+    let bound_generics: Vec<_> = (0..bound_args.len())
+        .map(|index| format_ident!("{}BOUND_{}", IDENT_PREFIX, index))
+        .collect();
+
+    items.push(syn::parse2(quote! {
+        #[allow(non_camel_case_types)]
+        pub struct #marker_name< #operator_generic #(, #bound_generics)* >(
+            core::marker::PhantomData<( #operator_generic, #(#bound_generics,)* )>
+        );
+    })?);
+
+    // applying the marker prepends the bound arguments
+    for arity in 0..=MAX_OPERATOR_ARITY {
+        let arg_generics = arg_generics(arity);
+        let applied = quote! {
+            ::typ::Apply<( #(#bound_generics,)* #(#arg_generics,)* )>
+        };
+        items.push(syn::parse2(quote! {
+            impl< #operator_generic #(, #bound_generics)* #(, #arg_generics)* >
+                ::typ::Apply<( #(#arg_generics,)* )>
+                for #marker_name< #operator_generic #(, #bound_generics)* >
+            where
+                #operator_generic: #applied
+            {
+                type Output = <#operator_generic as #applied>::Output;
+            }
+        })?);
+    }
+
+    // the marker takes the arguments left by the operator
+    for arity in 0..=MAX_OPERATOR_ARITY.saturating_sub(bound_args.len()) {
+        let operator_trait = format_ident!("Operator{}", arity);
+        let bounded_trait = format_ident!("Operator{}", arity + bound_args.len());
+        items.push(syn::parse2(quote! {
+            impl< #operator_generic #(, #bound_generics)* > ::typ::#operator_trait
+                for #marker_name< #operator_generic #(, #bound_generics)* >
+            where
+                #operator_generic: ::typ::#bounded_trait
+            {
+            }
+        })?);
+    }
+
+    Ok(marker_type_var(
+        marker_name,
+        iter::once(operator).chain(bound_args).collect(),
+    ))
+}
+
+pub fn translate_compose_expr(
+    call: &ExprCall,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprCall { args, .. } = call;
+
+    // sanity check
+    if args.len() != 2 {
+        return Err(Error::new(
+            args.span(),
+            "compose takes exactly two operators",
+        ));
+    }
+
+    let operators: Vec<_> = args
+        .iter()
+        .map(|arg| translate_expr(arg, env, items))
+        .try_collect()?;
+
+    // the marker applies the outer operator on the output of the inner one
+    let marker_name = env
+        .register_trait_name(&format!("{}Compose_", IDENT_PREFIX))
+        .expect("the trait name cannot proper prefix of existing trait names");
+    let outer = format_ident!("{}OUTER", IDENT_PREFIX);
+    let inner = format_ident!("{}INNER", IDENT_PREFIX);
+
+    items.push(syn::parse2(quote! {
+        #[allow(non_camel_case_types)]
+        pub struct #marker_name<#outer, #inner>(core::marker::PhantomData<(#outer, #inner)>);
+    })?);
+
+    for arity in 0..=MAX_OPERATOR_ARITY {
+        let arg_generics = arg_generics(arity);
+        let inner_output = quote! {
+            <#inner as ::typ::Apply<( #(#arg_generics,)* )>>::Output
+        };
+        items.push(syn::parse2(quote! {
+            impl< #outer, #inner #(, #arg_generics)* >
+                ::typ::Apply<( #(#arg_generics,)* )>
+                for #marker_name<#outer, #inner>
+            where
+                #inner: ::typ::Apply<( #(#arg_generics,)* )>,
+                #outer: ::typ::Apply<(#inner_output,)>
+            {
+                type Output = <#outer as ::typ::Apply<(#inner_output,)>>::Output;
+            }
+        })?);
+
+        // the marker takes the arguments of the inner operator
+        let operator_trait = format_ident!("Operator{}", arity);
+        items.push(syn::parse2(quote! {
+            impl<#outer, #inner> ::typ::#operator_trait for #marker_name<#outer, #inner>
+            where
+                #outer: ::typ::Operator1,
+                #inner: ::typ::#operator_trait
+            {
+            }
+        })?);
+    }
+
+    Ok(marker_type_var(marker_name, operators))
+}
+
+fn arg_generics(arity: usize) -> Vec<Ident> {
+    (0..arity)
+        .map(|index| format_ident!("{}ARG_{}", IDENT_PREFIX, index))
+        .collect()
+}
+
+fn marker_type_var(marker_name: Ident, args: Vec<TypeVar>) -> TypeVar {
+    TypeVar::Path(TypePathVar {
+        qself: None,
+        path: PathVar {
+            segments: vec![SegmentVar {
+                ident: marker_name,
+                arguments: PathArgumentsVar::AngleBracketed(args),
+            }],
+        },
+    })
+}
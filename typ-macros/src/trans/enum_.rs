@@ -0,0 +1,73 @@
+use super::*;
+
+pub fn translate_enum(enum_: &ItemEnum) -> syn::Result<TokenStream> {
+    let ItemEnum {
+        attrs,
+        vis,
+        ident: trait_name,
+        generics,
+        variants,
+        ..
+    } = enum_;
+
+    // sanity check
+    check_no_generics(generics)?;
+
+    // a private supertrait keeps the trait from being implemented outside the enum
+    let mod_name = format_ident!("{}mod_{}", IDENT_PREFIX, trait_name);
+    let sealed_trait = quote! { #mod_name::Sealed };
+
+    // generate one struct per variant
+    let variant_items: Vec<_> = variants
+        .iter()
+        .map(|variant| -> syn::Result<_> {
+            let Variant {
+                attrs,
+                ident: type_name,
+                fields,
+                discriminant,
+                ..
+            } = variant;
+
+            if let Some((eq, _)) = discriminant {
+                return Err(Error::new(eq.span(), "discriminant is not supported"));
+            }
+
+            let Record {
+                item,
+                generics,
+                predicates,
+            } = translate_record(attrs, vis, type_name, fields)?;
+
+            Ok(quote! {
+                #item
+
+                #[allow(non_camel_case_types)]
+                impl< #(#generics),* > #trait_name for #type_name< #(#generics),* >
+                where
+                    #(#predicates),*
+                {}
+
+                #[allow(non_camel_case_types)]
+                impl< #(#generics),* > #sealed_trait for #type_name< #(#generics),* >
+                where
+                    #(#predicates),*
+                {}
+            })
+        })
+        .try_collect()?;
+
+    let expanded = quote! {
+        #(#attrs)*
+        #vis trait #trait_name: #sealed_trait {}
+
+        #(#variant_items)*
+
+        #[allow(non_snake_case)]
+        mod #mod_name {
+            pub trait Sealed {}
+        }
+    };
+
+    Ok(expanded)
+}
@@ -6,6 +6,8 @@ pub fn translate_fn(
     block: &Block,
     self_ty: Option<&Type>,
     impl_generics: Option<&Generics>,
+    impl_trait: Option<&Path>,
+    registry: &Rc<Registry>,
 ) -> syn::Result<TokenStream> {
     let Signature {
         ident: fn_name,
@@ -36,6 +38,16 @@ pub fn translate_fn(
         return Err(Error::new(var.span(), "variadic argument is not supported"));
     }
 
+    if impl_trait.is_some() {
+        if let Visibility::Inherited = vis {
+        } else {
+            return Err(Error::new(
+                vis.span(),
+                "visibility qualifiers are not permitted in trait impl",
+            ));
+        }
+    }
+
     // reject lifetime and const generics
     if let Some(impl_generics) = impl_generics {
         for param in impl_generics.params.iter() {
@@ -64,7 +76,7 @@ pub fn translate_fn(
     }
 
     // create root scope
-    let mut env = Env::new(fn_name.clone());
+    let mut env = Env::new(fn_name.clone(), registry.clone());
 
     // check if impl and fn generic names coincide with each other
     if let Some(impl_generics) = impl_generics {
@@ -144,13 +156,18 @@ pub fn translate_fn(
     };
 
     // insert free quantifiers and predicates from fn generics
-    {
+    let fn_generic_vars: Vec<_> = {
         // insert free quantifiers
-        for param in fn_generics.params.iter() {
-            if let GenericParam::Type(TypeParam { ident, .. }) = param {
-                env.insert_free_quantifier(ident.to_owned());
-            }
-        }
+        let fn_generic_vars: Vec<_> = fn_generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(TypeParam { ident, .. }) => {
+                    Some(env.insert_free_quantifier(ident.to_owned()))
+                }
+                _ => None,
+            })
+            .collect();
 
         // insert trait bounds
         for param in fn_generics.params.iter() {
@@ -164,7 +181,9 @@ pub fn translate_fn(
                 env.insert_predicate(predicate);
             }
         }
-    }
+
+        fn_generic_vars
+    };
 
     // translate function arguments into types and trait bounds
     let (fn_args, fn_predicates): (Vec<_>, Vec<_>) = inputs
@@ -197,8 +216,9 @@ pub fn translate_fn(
         env.insert_bounded_quantifier(format_ident!("self"), false, var.clone());
     }
 
-    // translate block
+    // translate block, where early returns are lowered to if-else branches
     let mut items = vec![];
+    let block = lower_return_block(block)?;
     let output = translate_block(&block, &mut env, &mut items)?;
 
     // insert trait bound for output type
@@ -223,61 +243,61 @@ pub fn translate_fn(
 
     // generate trait names
     let trait_name = format_ident!("{}", fn_name);
+    let impl_trait_path = match impl_trait {
+        Some(path) => {
+            // the method implements the trait of the same name next to the impl trait
+            let mut path = path.parse_pure_path(&mut vec![])?;
+            rebase_relative_path(&mut path.segments);
+            path.segments.last_mut().unwrap().ident = trait_name.clone();
+            let path = path.substitute(&env, &subsitution);
+            quote! { #path }
+        }
+        None => quote! { #trait_name },
+    };
+
+    // generate impl items
+    let input_types: Vec<_> = fn_args
+        .iter()
+        .map(|arg| arg.substitute(&env, &subsitution))
+        .collect();
+
+    // generics not determined by the arguments are passed explicitly
+    let explicit_generics: Vec<_> = fn_generic_vars
+        .iter()
+        .filter(|var| !fn_args.iter().any(|arg| arg.contains_variable(var)))
+        .map(|var| {
+            let generic = &subsitution[var];
+            quote! { #generic }
+        })
+        .collect();
+    let trait_args: Vec<_> = explicit_generics
+        .into_iter()
+        .chain(input_types.iter().map(|ty| quote! { #ty }))
+        .collect();
 
     // generate trait item
-    let trait_item: ItemTrait = {
-        let num_args = fn_args.len();
-        let args: Vec<_> = (0..num_args)
-            .map(|idx| format_ident!("{}ARG_{}", IDENT_PREFIX, idx))
+    let trait_item = {
+        let arg_bounds: Vec<_> = iter::repeat_n(vec![], trait_args.len() - input_types.len())
+            .chain(fn_predicates.iter().map(|predicate| match predicate {
+                WherePredicateVar::Type(PredicateTypeVar { bounds, .. }) => bounds.to_owned(),
+            }))
             .collect();
-        let arg_predicates: Vec<_> = args
-            .iter()
-            .zip(fn_predicates.iter())
-            .map(|(arg, predicate)| match predicate {
-                WherePredicateVar::Type(PredicateTypeVar { bounds, .. }) => {
-                    let bounds: Vec<_> = bounds
-                        .iter()
-                        .map(|bound| bound.substitute(&env, &subsitution))
-                        .collect();
-                    quote! { #arg: #(#bounds)+* }
-                }
-            })
-            .collect();
-        let output_predicate = output_bounds.as_ref().map(|bounds| {
-            WherePredicateVar::Type(PredicateTypeVar {
-                bounded_ty: syn::parse2::<Type>(quote! { Self::Output })
-                    .unwrap()
-                    .parse_pure_type(&mut vec![])
-                    .unwrap(),
-                bounds: bounds.to_owned(),
-            })
-            .substitute(&env, &subsitution)
-        });
-
-        syn::parse2(quote! {
-            #[allow(non_snake_case)]
-            pub trait #trait_name < #(#args),* >
-            where
-                #(#arg_predicates,)*
-                #output_predicate
-            {
-                type Output;
-            }
-        })?
+        translate_fn_trait(
+            &trait_name,
+            &arg_bounds,
+            output_bounds.as_ref(),
+            &env,
+            &subsitution,
+        )?
     };
 
-    // generate impl items
+    let predicates: Vec<_> = env
+        .predicates()
+        .into_iter()
+        .map(|predicate| predicate.substitute(&env, &subsitution))
+        .collect();
+    let output = output.substitute(&env, &subsitution);
     let impl_item: ItemImpl = {
-        let input_types: Vec<_> = fn_args
-            .iter()
-            .map(|arg| arg.substitute(&env, &subsitution))
-            .collect();
-        let predicates: Vec<_> = env
-            .predicates()
-            .into_iter()
-            .map(|predicate| predicate.substitute(&env, &subsitution))
-            .collect();
-        let output = output.substitute(&env, &subsitution);
         let self_ty_tokens = match &self_ty_var {
             Some(var) => {
                 let subsituted = var.substitute(&env, &subsitution);
@@ -287,7 +307,7 @@ pub fn translate_fn(
         };
 
         syn::parse2(quote! {
-            impl<#(#input_generics),*>  #trait_name< #(#input_types),* > for #self_ty_tokens
+            impl<#(#input_generics),*>  #impl_trait_path< #(#trait_args),* > for #self_ty_tokens
             where
                 #(#predicates),*
             {
@@ -297,15 +317,29 @@ pub fn translate_fn(
     };
 
     // push items to child module
-    items.push(Item::Trait(trait_item));
+    if impl_trait.is_none() {
+        items.push(Item::Trait(trait_item));
+    }
     items.push(Item::Impl(impl_item));
 
-    let expanded = {
-        let num_args = fn_args.len();
+    let mod_name = format_ident!("{}mod_{}", IDENT_PREFIX, fn_name);
+    let expanded = if impl_trait.is_some() {
+        // the trait is declared elsewhere, so keep the module in an anonymous scope
+        quote! {
+            const _: () = {
+                #[allow(non_snake_case)]
+                mod #mod_name {
+                    use super::*;
+
+                    #(#items)*
+                }
+            };
+        }
+    } else {
+        let num_args = trait_args.len();
         let args: Vec<_> = (0..num_args)
             .map(|idx| format_ident!("{}ARG_{}", IDENT_PREFIX, idx))
             .collect();
-        let mod_name = format_ident!("{}mod_{}", IDENT_PREFIX, fn_name);
         let type_name = format_ident!("{}Op", fn_name);
         let type_item = if self_ty_var.is_some() {
             let self_arg = format_ident!("{}SELF", IDENT_PREFIX);
@@ -318,8 +352,40 @@ pub fn translate_fn(
             }
         };
 
+        // a zero-sized marker in the module passes the operator as a value
+        let marker_name = format_ident!("{}Fn", fn_name);
+        let (marker_use, marker_items) = if self_ty_var.is_none() {
+            let operator_impl = (num_args <= MAX_OPERATOR_ARITY).then(|| {
+                let operator_trait = format_ident!("Operator{}", num_args);
+                quote! {
+                    impl ::typ::#operator_trait for #marker_name {}
+                }
+            });
+            let marker_use = quote! {
+                #[allow(unused_imports)]
+                #vis use #mod_name :: #marker_name;
+            };
+            let marker_items = quote! {
+                #[allow(dead_code)]
+                pub struct #marker_name;
+
+                impl<#(#input_generics),*> ::typ::Apply<( #(#trait_args,)* )> for #marker_name
+                where
+                    #(#predicates),*
+                {
+                    type Output = #output;
+                }
+
+                #operator_impl
+            };
+            (marker_use, marker_items)
+        } else {
+            (quote! {}, quote! {})
+        };
+
         quote! {
             #vis use #mod_name :: #trait_name;
+            #marker_use
             #type_item
 
             #[allow(non_snake_case)]
@@ -327,9 +393,57 @@ pub fn translate_fn(
                 use super::*;
 
                 #(#items)*
+                #marker_items
             }
         }
     };
 
     Ok(expanded)
 }
+
+pub fn translate_fn_trait(
+    trait_name: &Ident,
+    arg_bounds: &[Vec<TypeParamBoundVar>],
+    output_bounds: Option<&Vec<TypeParamBoundVar>>,
+    env: &Env,
+    subsitution: &IndexMap<Shared<Variable>, Ident>,
+) -> syn::Result<ItemTrait> {
+    let args: Vec<_> = (0..arg_bounds.len())
+        .map(|idx| format_ident!("{}ARG_{}", IDENT_PREFIX, idx))
+        .collect();
+    let arg_predicates: Vec<_> = args
+        .iter()
+        .zip(arg_bounds.iter())
+        .filter(|(_, bounds)| !bounds.is_empty())
+        .map(|(arg, bounds)| {
+            let bounds: Vec<_> = bounds
+                .iter()
+                .map(|bound| bound.substitute(env, subsitution))
+                .collect();
+            quote! { #arg: #(#bounds)+* }
+        })
+        .collect();
+    let output_predicate = output_bounds
+        .filter(|bounds| !bounds.is_empty())
+        .map(|bounds| {
+            WherePredicateVar::Type(PredicateTypeVar {
+                bounded_ty: syn::parse2::<Type>(quote! { Self::Output })
+                    .unwrap()
+                    .parse_pure_type(&mut vec![])
+                    .unwrap(),
+                bounds: bounds.to_owned(),
+            })
+            .substitute(env, subsitution)
+        });
+
+    syn::parse2(quote! {
+        #[allow(non_snake_case)]
+        pub trait #trait_name < #(#args),* >
+        where
+            #(#arg_predicates,)*
+            #output_predicate
+        {
+            type Output;
+        }
+    })
+}
@@ -14,52 +14,40 @@ where
         ..
     } = if_;
 
-    // generate predicate tokens
-    let condition = {
-        let cond_ty = translate_expr(&*cond, env, items)?;
-        let eq_trait: PathVar =
-            syn::parse2::<Path>(quote! { typenum::type_operators::IsEqual<typenum::B1> })
-                .unwrap()
-                .parse_pure_path(&mut vec![])
-                .unwrap();
-        let path = {
-            let mut path = eq_trait.clone();
-            path.segments.push(SegmentVar {
-                ident: format_ident!("Output"),
-                arguments: PathArgumentsVar::None,
-            });
-            path
+    // if-let is a match with a catch-all arm
+    if let Expr::Let(ExprLet { pat, expr, .. }) = &**cond {
+        let else_expr = match else_branch {
+            Some((_else, else_expr)) => quote! { #else_expr },
+            None => quote! { {} },
         };
-        let output = TypeVar::Path(TypePathVar {
-            qself: Some(QSelfVar {
-                ty: Box::new(cond_ty.clone()),
-                position: eq_trait.segments.len(),
-            }),
-            path,
-        });
-        let apply_predicate = WherePredicateVar::Type(PredicateTypeVar {
-            bounded_ty: cond_ty,
-            bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
-                modifier: TraitBoundModifierVar::None,
-                path: eq_trait,
-            })],
-        });
-        let output_predicate = WherePredicateVar::Type(PredicateTypeVar {
-            bounded_ty: output.clone(),
-            bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
-                modifier: TraitBoundModifierVar::None,
-                path: syn::parse2::<Path>(quote! { typenum::marker_traits::Bit })
-                    .unwrap()
-                    .parse_pure_path(&mut vec![])
-                    .unwrap(),
-            })],
-        });
+        let match_: ExprMatch = syn::parse2(quote! {
+            match #expr {
+                #pat => #then_branch
+                _ => #else_expr
+            }
+        })?;
+        return translate_lowered_match_expr(&match_, cond.span(), env, items);
+    }
 
-        env.insert_predicate(apply_predicate);
-        env.insert_predicate(output_predicate);
+    let translate_else = else_branch.as_ref().map(|(_else, else_expr)| {
+        move |env: &mut Env, items: &mut Vec<Item>| translate_expr(else_expr, env, items)
+    });
+    translate_if_else(cond, then_branch, translate_else, env, items)
+}
 
-        output
-    };
+/// Translates an if expression whose else branch is translated by the given function.
+pub fn translate_if_else<F>(
+    cond: &Expr,
+    then_branch: &Block,
+    translate_else: Option<F>,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar>
+where
+    F: FnOnce(&mut Env, &mut Vec<Item>) -> syn::Result<TypeVar>,
+{
+    // generate predicate tokens
+    let condition = translate_cond_expr(cond, env, items)?;
 
     // save quantifiers
     let mutable_quantifiers = env.mutable_quantifiers();
@@ -113,8 +101,8 @@ where
 
     // generate impl items
     let impls = {
-        let impls: Vec<ItemImpl> = match else_branch {
-            Some((_else, else_expr)) => {
+        let impls: Vec<ItemImpl> = match translate_else {
+            Some(translate_else) => {
                 let if_impls: Vec<_> = {
                     let mut branched_env = env.branch();
                     let then_output = translate_block(then_branch, &mut branched_env, items)?
@@ -167,7 +155,7 @@ where
 
                 let else_impls: Vec<_> = {
                     let mut branched_env = env.branch();
-                    let else_output = translate_expr(&**else_expr, &mut branched_env, items)?
+                    let else_output = translate_else(&mut branched_env, items)?
                         .substitute(&branched_env, &substitution);
                     let predicates: Vec<_> = branched_env
                         .predicates()
@@ -418,3 +406,54 @@ where
 
     Ok(output)
 }
+
+// the condition value, which must be typenum::B1 or typenum::B0
+pub fn translate_cond_expr(
+    cond: &Expr,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let cond_ty = translate_expr(cond, env, items)?;
+    let eq_trait: PathVar =
+        syn::parse2::<Path>(quote! { typenum::type_operators::IsEqual<typenum::B1> })
+            .unwrap()
+            .parse_pure_path(&mut vec![])
+            .unwrap();
+    let path = {
+        let mut path = eq_trait.clone();
+        path.segments.push(SegmentVar {
+            ident: format_ident!("Output"),
+            arguments: PathArgumentsVar::None,
+        });
+        path
+    };
+    let output = TypeVar::Path(TypePathVar {
+        qself: Some(QSelfVar {
+            ty: Box::new(cond_ty.clone()),
+            position: eq_trait.segments.len(),
+        }),
+        path,
+    });
+    let apply_predicate = WherePredicateVar::Type(PredicateTypeVar {
+        bounded_ty: cond_ty,
+        bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
+            modifier: TraitBoundModifierVar::None,
+            path: eq_trait,
+        })],
+    });
+    let output_predicate = WherePredicateVar::Type(PredicateTypeVar {
+        bounded_ty: output.clone(),
+        bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
+            modifier: TraitBoundModifierVar::None,
+            path: syn::parse2::<Path>(quote! { typenum::marker_traits::Bit })
+                .unwrap()
+                .parse_pure_path(&mut vec![])
+                .unwrap(),
+        })],
+    });
+
+    env.insert_predicate(apply_predicate);
+    env.insert_predicate(output_predicate);
+
+    Ok(output)
+}
@@ -0,0 +1,140 @@
+use super::*;
+
+pub fn translate_impl(impl_: &ItemImpl, registry: &Rc<Registry>) -> syn::Result<TokenStream> {
+    let ItemImpl {
+        defaultness,
+        unsafety,
+        generics,
+        trait_,
+        self_ty,
+        items,
+        ..
+    } = impl_;
+
+    // sanity check
+    if let Some(defaultness) = defaultness {
+        return Err(Error::new(
+            defaultness.span(),
+            "default keyword is not supported",
+        ));
+    }
+    if let Some(unsafety) = unsafety {
+        return Err(Error::new(
+            unsafety.span(),
+            "unsafe keyword is not supported",
+        ));
+    }
+    let trait_path = match trait_ {
+        Some((Some(bang), _, _)) => {
+            return Err(Error::new(bang.span(), "negative impl is not supported"));
+        }
+        Some((None, path, _)) => {
+            if let Some(segment) = path
+                .segments
+                .iter()
+                .find(|segment| !segment.arguments.is_empty())
+            {
+                return Err(Error::new(
+                    segment.arguments.span(),
+                    "type parameters on trait are not supported",
+                ));
+            }
+            Some(path)
+        }
+        None => None,
+    };
+
+    // the methods must match the trait exactly if it is declared in a typ! block
+    if let Some((path, trait_decl)) =
+        trait_path.and_then(|path| Some((path, registry.trait_decl(path)?)))
+    {
+        let method_idents: Vec<_> = items
+            .iter()
+            .filter_map(|item| match item {
+                ImplItem::Method(ImplItemMethod { sig, .. }) => Some(&sig.ident),
+                _ => None,
+            })
+            .collect();
+
+        if let Some(ident) = method_idents
+            .iter()
+            .find(|ident| !trait_decl.methods.contains(ident))
+        {
+            return Err(Error::new(
+                ident.span(),
+                format!(
+                    "method `{}` is not a member of trait `{}`",
+                    ident, trait_decl.ident
+                ),
+            ));
+        }
+
+        let missing: Vec<_> = trait_decl
+            .methods
+            .iter()
+            .filter(|method| !method_idents.contains(method))
+            .map(|method| format!("`{}`", method))
+            .collect();
+        if !missing.is_empty() {
+            return Err(Error::new(
+                path.span(),
+                format!(
+                    "not all trait methods are implemented, missing: {}",
+                    missing.join(", ")
+                ),
+            ));
+        }
+    }
+
+    let items_tokens: Vec<_> = items
+        .iter()
+        .map(|item| -> syn::Result<_> {
+            let tokens = match item {
+                ImplItem::Method(method) => {
+                    let ImplItemMethod {
+                        sig, block, vis, ..
+                    } = method;
+                    translate_fn(
+                        vis,
+                        sig,
+                        block,
+                        Some(&**self_ty),
+                        Some(generics),
+                        trait_path,
+                        registry,
+                    )?
+                }
+                _ => {
+                    return Err(Error::new(item.span(), "unsupported item"));
+                }
+            };
+
+            Ok(tokens)
+        })
+        .try_collect()?;
+
+    // implement the trait itself if none of the methods is named after it
+    let marker_impl = trait_path.and_then(|path| {
+        let trait_name = &path.segments.last().unwrap().ident;
+        let has_method = items.iter().any(|item| match item {
+            ImplItem::Method(method) => &method.sig.ident == trait_name,
+            _ => false,
+        });
+
+        if has_method {
+            None
+        } else {
+            let (impl_generics, _, where_clause) = generics.split_for_impl();
+            Some(quote! {
+                #[allow(non_camel_case_types)]
+                impl #impl_generics #path for #self_ty #where_clause {}
+            })
+        }
+    });
+
+    let expanded = quote! {
+        #(#items_tokens)*
+        #marker_impl
+    };
+    Ok(expanded)
+}
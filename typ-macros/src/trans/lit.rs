@@ -6,10 +6,13 @@ pub fn translate_lit_expr(
     _items: &mut Vec<Item>,
 ) -> syn::Result<TypeVar> {
     let ExprLit { lit, .. } = expr;
+    translate_lit(lit, false)
+}
 
+pub fn translate_lit(lit: &Lit, negative: bool) -> syn::Result<TypeVar> {
     // parse literal
     let lit_tokens = match lit {
-        Lit::Bool(LitBool { value, .. }) => {
+        Lit::Bool(LitBool { value, .. }) if !negative => {
             if *value {
                 quote! { typenum::B1 }
             } else {
@@ -21,18 +24,28 @@ pub fn translate_lit_expr(
                 let value: u128 = int_.base10_parse()?;
                 if value == 0 {
                     quote! { typenum::Z0 }
+                } else if negative {
+                    let ty = int_to_typenum(value);
+                    quote! { typenum::int::NInt<#ty> }
                 } else {
                     let ty = int_to_typenum(value);
                     quote! { typenum::int::PInt<#ty> }
                 }
             }
-            "u" => {
+            "u" if !negative => {
                 let value: u128 = int_.base10_parse()?;
                 let ty = int_to_typenum(value);
                 ty
             }
+            "u" => {
+                return Err(Error::new(
+                    int_.span(),
+                    "unsigned integer cannot be negated",
+                ))
+            }
             _ => return Err(Error::new(int_.span(), "unsupported literal suffix")),
         },
+        Lit::Bool(_) => return Err(Error::new(lit.span(), "bit cannot be negated")),
         _ => return Err(Error::new(lit.span(), "unsupported literal")),
     };
     let lit_ty: TypeVar = syn::parse2::<Type>(lit_tokens)
@@ -0,0 +1,519 @@
+use super::*;
+
+pub fn translate_while_expr(
+    while_: &ExprWhile,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprWhile {
+        label, cond, body, ..
+    } = while_;
+
+    // sanity check
+    if let Some(label) = label {
+        return Err(Error::new(label.span(), "loop label is not supported"));
+    }
+    if let Expr::Let(let_) = &**cond {
+        return Err(Error::new(let_.span(), "while let is not supported"));
+    }
+
+    translate_while_loop(cond, env, items, |body_env, items| {
+        translate_block(body, body_env, items)
+    })?;
+    Ok(unit_type_var())
+}
+
+fn translate_while_loop<F>(
+    cond: &Expr,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+    translate_body: F,
+) -> syn::Result<()>
+where
+    F: FnOnce(&mut Env, &mut Vec<Item>) -> syn::Result<TypeVar>,
+{
+    // generate trait names
+    let loop_trait_name = env
+        .register_trait_name(&format!("{}While_", IDENT_PREFIX))
+        .expect("the trait name cannot proper prefix of existing trait names");
+    let step_trait_name = env
+        .register_trait_name(&format!("{}WhileStep_", IDENT_PREFIX))
+        .expect("the trait name cannot proper prefix of existing trait names");
+
+    let LoopState {
+        mut loop_env,
+        state_idents,
+        state_names,
+        substitution,
+        parent_generics,
+        state_generics,
+    } = LoopState::new(env);
+    let generics: Vec<_> = substitution.values().collect();
+
+    // the condition is evaluated on the current state
+    let cond_value = translate_cond_expr(cond, &mut loop_env, items)?;
+    let cond_value = cond_value.substitute(&loop_env, &substitution);
+    let loop_predicates: Vec<_> = loop_env
+        .predicates()
+        .into_iter()
+        .map(|predicate| predicate.substitute(&loop_env, &substitution))
+        .collect();
+
+    // the body computes the next state
+    let mut body_env = loop_env.branch();
+    translate_body(&mut body_env, items)?;
+    let next_state = LoopState::values(&state_idents, &body_env, &substitution);
+    let body_predicates: Vec<_> = body_env
+        .predicates()
+        .into_iter()
+        .map(|predicate| predicate.substitute(&body_env, &substitution))
+        .collect();
+
+    let cond_generic = format_ident!("{}CONDITION_GENERIC", IDENT_PREFIX);
+    let step = quote! { #step_trait_name< #(#generics,)* #cond_value > };
+    let next_loop = quote! { #loop_trait_name< #(#parent_generics,)* #(#next_state),* > };
+    let step_outputs: Vec<_> = state_names
+        .iter()
+        .map(|name| quote! { type #name = <() as #step>::#name; })
+        .collect();
+    let next_outputs: Vec<_> = state_names
+        .iter()
+        .map(|name| quote! { type #name = <() as #next_loop>::#name; })
+        .collect();
+
+    let loop_items: Vec<Item> = vec![
+        syn::parse2(quote! {
+            #[allow(non_snake_case, non_camel_case_types)]
+            pub trait #loop_trait_name < #(#generics),* > {
+                #(type #state_names;)*
+            }
+        })?,
+        syn::parse2(quote! {
+            #[allow(non_snake_case, non_camel_case_types)]
+            pub trait #step_trait_name < #(#generics,)* #cond_generic > {
+                #(type #state_names;)*
+            }
+        })?,
+        syn::parse2(quote! {
+            impl< #(#generics),* > #loop_trait_name< #(#generics),* > for ()
+            where
+                #(#loop_predicates,)*
+                (): #step
+            {
+                #(#step_outputs)*
+            }
+        })?,
+        syn::parse2(quote! {
+            impl< #(#generics),* > #step_trait_name< #(#generics,)* typenum::B1 > for ()
+            where
+                #(#body_predicates,)*
+                (): #next_loop
+            {
+                #(#next_outputs)*
+            }
+        })?,
+        syn::parse2(quote! {
+            impl< #(#generics),* > #step_trait_name< #(#generics,)* typenum::B0 > for () {
+                #(type #state_names = #state_generics;)*
+            }
+        })?,
+    ];
+    items.extend(loop_items);
+
+    // assign the final state
+    let initial_state: Vec<_> = state_idents
+        .iter()
+        .map(|ident| TypeVar::Var(env.get_variable(ident).unwrap()))
+        .collect();
+    let outputs = apply_state_trait(&loop_trait_name, initial_state, &state_names, env);
+    for (ident, output) in state_idents.iter().zip(outputs) {
+        env.assign_quantifier(ident, output)?;
+    }
+
+    Ok(())
+}
+
+pub fn translate_loop_expr(
+    loop_: &ExprLoop,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprLoop { label, body, .. } = loop_;
+
+    // sanity check
+    if let Some(label) = label {
+        return Err(Error::new(label.span(), "loop label is not supported"));
+    }
+
+    // the body evaluates to a (break, value) pair
+    let body = lower_break_block(body)?;
+
+    // generate trait names
+    let loop_trait_name = env
+        .register_trait_name(&format!("{}Loop_", IDENT_PREFIX))
+        .expect("the trait name cannot proper prefix of existing trait names");
+    let step_trait_name = env
+        .register_trait_name(&format!("{}LoopStep_", IDENT_PREFIX))
+        .expect("the trait name cannot proper prefix of existing trait names");
+
+    let LoopState {
+        loop_env,
+        state_idents,
+        state_names,
+        substitution,
+        parent_generics,
+        state_generics,
+    } = LoopState::new(env);
+    let generics: Vec<_> = substitution.values().collect();
+
+    // the body computes the signal and the next state
+    let mut body_env = loop_env.branch();
+    let signal = translate_block(&body, &mut body_env, items)?;
+    let signal = signal.substitute(&body_env, &substitution);
+    let next_state = LoopState::values(&state_idents, &body_env, &substitution);
+    let body_predicates: Vec<_> = body_env
+        .predicates()
+        .into_iter()
+        .map(|predicate| predicate.substitute(&body_env, &substitution))
+        .collect();
+
+    let value_generic = format_ident!("{}VALUE_GENERIC", IDENT_PREFIX);
+    let step = quote! { #step_trait_name< #(#parent_generics,)* #(#next_state,)* #signal > };
+    let next_loop = quote! { #loop_trait_name< #(#generics),* > };
+    let step_outputs: Vec<_> = state_names
+        .iter()
+        .map(|name| quote! { type #name = <() as #step>::#name; })
+        .collect();
+    let next_outputs: Vec<_> = state_names
+        .iter()
+        .map(|name| quote! { type #name = <() as #next_loop>::#name; })
+        .collect();
+
+    let loop_items: Vec<Item> = vec![
+        syn::parse2(quote! {
+            #[allow(non_snake_case, non_camel_case_types)]
+            pub trait #loop_trait_name < #(#generics),* > {
+                type Output;
+                #(type #state_names;)*
+            }
+        })?,
+        syn::parse2(quote! {
+            #[allow(non_snake_case, non_camel_case_types)]
+            pub trait #step_trait_name < #(#generics,)* #value_generic > {
+                type Output;
+                #(type #state_names;)*
+            }
+        })?,
+        syn::parse2(quote! {
+            impl< #(#generics),* > #loop_trait_name< #(#generics),* > for ()
+            where
+                #(#body_predicates,)*
+                (): #step
+            {
+                type Output = <() as #step>::Output;
+                #(#step_outputs)*
+            }
+        })?,
+        syn::parse2(quote! {
+            impl< #(#generics,)* #value_generic >
+                #step_trait_name< #(#generics,)* (typenum::B1, #value_generic) > for ()
+            {
+                type Output = #value_generic;
+                #(type #state_names = #state_generics;)*
+            }
+        })?,
+        syn::parse2(quote! {
+            impl< #(#generics,)* #value_generic >
+                #step_trait_name< #(#generics,)* (typenum::B0, #value_generic) > for ()
+            where
+                (): #next_loop
+            {
+                type Output = <() as #next_loop>::Output;
+                #(#next_outputs)*
+            }
+        })?,
+    ];
+    items.extend(loop_items);
+
+    // assign the final state, and return the broken value
+    let initial_state: Vec<_> = state_idents
+        .iter()
+        .map(|ident| TypeVar::Var(env.get_variable(ident).unwrap()))
+        .collect();
+    let output_names: Vec<_> = state_names
+        .iter()
+        .cloned()
+        .chain(iter::once(format_ident!("Output")))
+        .collect();
+    let mut outputs = apply_state_trait(&loop_trait_name, initial_state, &output_names, env);
+    let output = outputs.pop().unwrap();
+    for (ident, state) in state_idents.iter().zip(outputs) {
+        env.assign_quantifier(ident, state)?;
+    }
+
+    Ok(output)
+}
+
+pub fn translate_for_expr(
+    for_: &ExprForLoop,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprForLoop {
+        label,
+        pat,
+        expr,
+        body,
+        ..
+    } = for_;
+
+    // sanity check
+    if let Some(label) = label {
+        return Err(Error::new(label.span(), "loop label is not supported"));
+    }
+
+    // lower to a while loop over a hidden counter or list
+    match &**expr {
+        Expr::Range(range) => {
+            let while_ = lower_range_loop(range, pat, body)?;
+            translate_expr(&while_, env, items)
+        }
+        list => translate_list_loop(list, pat, body, env, items),
+    }
+}
+
+fn lower_range_loop(range: &ExprRange, pat: &Pat, body: &Block) -> syn::Result<Expr> {
+    let ExprRange {
+        from, limits, to, ..
+    } = range;
+    let (from, to) = match (from, to) {
+        (Some(from), Some(to)) => (from, to),
+        _ => {
+            return Err(Error::new(
+                range.span(),
+                "the range must have both the start and the end",
+            ))
+        }
+    };
+
+    // step by signed one if the range starts with a signed literal, otherwise by unsigned one
+    let is_signed = match &**from {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int_),
+            ..
+        }) => int_.suffix() != "u",
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_), ..
+        }) => true,
+        _ => false,
+    };
+    let one = if is_signed {
+        quote! { 1 }
+    } else {
+        quote! { 1u }
+    };
+    let compare = match limits {
+        RangeLimits::HalfOpen(_) => quote! { < },
+        RangeLimits::Closed(_) => quote! { <= },
+    };
+    let index = format_ident!("{}FOR_INDEX", IDENT_PREFIX);
+    let end = format_ident!("{}FOR_END", IDENT_PREFIX);
+    let bind = match pat {
+        Pat::Wild(_) => quote! {},
+        Pat::Ident(_) | Pat::Type(_) => quote! { let #pat = #index; },
+        _ => {
+            return Err(Error::new(
+                pat.span(),
+                "only an identifier is supported in a range loop",
+            ))
+        }
+    };
+
+    // the end is evaluated once before the loop
+    syn::parse2(quote! {
+        {
+            let mut #index = #from;
+            let #end = #to;
+            while #index #compare #end {
+                #bind
+                #index = #index + #one;
+                #body
+            }
+        }
+    })
+}
+
+fn translate_list_loop(
+    list: &Expr,
+    pat: &Pat,
+    body: &Block,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let cursor = format_ident!("{}FOR_LIST", IDENT_PREFIX);
+    let rest = format_ident!("{}FOR_REST", IDENT_PREFIX);
+
+    // find the list constructors of the enum the list is declared with
+    let list_ty = translate_expr(list, env, items)?;
+    let (cons_ident, nil_ident, head, tail) = env
+        .trait_bounds(&list_ty)
+        .into_iter()
+        .find_map(|TypeParamBoundVar::Trait(TraitBoundVar { path, .. })| {
+            let enum_ident = &path.segments.last()?.ident;
+            let (cons, nil) = env.registry().list_variants(enum_ident)?;
+            Some((
+                cons.ident.to_owned(),
+                nil.ident.to_owned(),
+                cons.fields[0].to_owned(),
+                cons.fields[1].to_owned(),
+            ))
+        })
+        .ok_or_else(|| {
+            Error::new(
+                list.span(),
+                "cannot find the list constructors, the list must be declared with an enum shaped like `enum List { Cons(_, List), Nil }` in the typ! block",
+            )
+        })?;
+
+    let cond: Expr = syn::parse2(quote! {
+        match #cursor { #cons_ident { .. } => true, #nil_ident => false }
+    })?;
+    let step: ExprMatch = syn::parse2(quote! {
+        match #cursor {
+            #cons_ident { #head: #pat, #tail: #rest } => {
+                #cursor = #rest;
+                #body
+            }
+        }
+    })?;
+
+    // identifiers in the loop pattern always bind the list items
+    env.sub_scope(|env| {
+        env.insert_bounded_quantifier(cursor, true, list_ty);
+        translate_while_loop(&cond, env, items, |body_env, items| {
+            translate_fresh_match_expr(&step, body_env, items)
+        })
+    })?;
+    Ok(unit_type_var())
+}
+
+// mutable variables are threaded through the loop as generics
+struct LoopState {
+    loop_env: Env,
+    state_idents: Vec<Ident>,
+    state_names: Vec<Ident>,
+    substitution: IndexMap<Shared<Variable>, Ident>,
+    parent_generics: Vec<Ident>,
+    state_generics: Vec<Ident>,
+}
+
+impl LoopState {
+    fn new(env: &Env) -> Self {
+        let mut state_idents: Vec<_> = env
+            .mutable_quantifiers()
+            .keys()
+            .map(|ident| (**ident).clone())
+            .collect();
+        state_idents.sort_by_cached_key(|ident| ident.to_string());
+
+        // bind each mutable variable to a fresh free quantifier
+        let n_parent = env.free_quantifiers().len();
+        let mut loop_env = env.branch();
+        for ident in state_idents.iter() {
+            let state =
+                loop_env.insert_free_quantifier(format_ident!("{}STATE_{}", IDENT_PREFIX, ident));
+            loop_env.insert_bounded_quantifier(ident.to_owned(), true, TypeVar::Var(state));
+        }
+
+        let substitution: IndexMap<_, _> = loop_env
+            .free_quantifiers()
+            .into_iter()
+            .enumerate()
+            .map(|(index, var)| (var, format_ident!("{}GENERIC_{}", IDENT_PREFIX, index)))
+            .collect();
+        let generics: Vec<_> = substitution.values().cloned().collect();
+        let (parent_generics, state_generics) = generics.split_at(n_parent);
+        let state_names = (0..state_idents.len())
+            .map(|index| format_ident!("{}State_{}", IDENT_PREFIX, index))
+            .collect();
+
+        Self {
+            parent_generics: parent_generics.to_vec(),
+            state_generics: state_generics.to_vec(),
+            loop_env,
+            state_idents,
+            state_names,
+            substitution,
+        }
+    }
+
+    fn values(
+        state_idents: &[Ident],
+        env: &Env,
+        substitution: &IndexMap<Shared<Variable>, Ident>,
+    ) -> Vec<Type> {
+        state_idents
+            .iter()
+            .map(|ident| {
+                env.get_variable(ident)
+                    .expect("please report bug: the variable is missing")
+                    .substitute(env, substitution)
+            })
+            .collect()
+    }
+}
+
+// apply the loop trait on parent free quantifiers and the state, and project each state
+fn apply_state_trait(
+    trait_name: &Ident,
+    state: Vec<TypeVar>,
+    state_names: &[Ident],
+    env: &mut Env,
+) -> Vec<TypeVar> {
+    let args: Vec<_> = env
+        .free_quantifiers()
+        .into_iter()
+        .map(TypeVar::Var)
+        .chain(state)
+        .collect();
+    let trait_ = PathVar {
+        segments: vec![SegmentVar {
+            ident: trait_name.to_owned(),
+            arguments: PathArgumentsVar::AngleBracketed(args),
+        }],
+    };
+    let bounded_ty = unit_type_var();
+
+    env.insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
+        bounded_ty: bounded_ty.clone(),
+        bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
+            modifier: TraitBoundModifierVar::None,
+            path: trait_.clone(),
+        })],
+    }));
+
+    state_names
+        .iter()
+        .map(|state_name| {
+            let mut path = trait_.clone();
+            path.segments.push(SegmentVar {
+                ident: state_name.to_owned(),
+                arguments: PathArgumentsVar::None,
+            });
+            TypeVar::Path(TypePathVar {
+                qself: Some(QSelfVar {
+                    ty: Box::new(bounded_ty.clone()),
+                    position: trait_.segments.len(),
+                }),
+                path,
+            })
+        })
+        .collect()
+}
+
+fn unit_type_var() -> TypeVar {
+    syn::parse2::<Type>(quote! { () })
+        .unwrap()
+        .parse_pure_type(&mut vec![])
+        .unwrap()
+}
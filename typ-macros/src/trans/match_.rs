@@ -0,0 +1,1121 @@
+use super::*;
+
+// the traits of a translated match, which are applied on the matched type
+struct MatchTraits {
+    pub match_trait_name: Ident,
+    pub assign_trait_names: Vec<(Rc<Ident>, Ident)>,
+    pub parent_free_quantifiers: Vec<Shared<Variable>>,
+    pub pattern: TypeVar,
+}
+
+struct ArmAttributes {
+    pub generics_attr: Option<GenericsAttr>,
+    pub capture_attr: Option<CaptureAttr>,
+    pub default_attr: Option<()>,
+}
+
+pub fn translate_match_expr(
+    match_: &ExprMatch,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar>
+where
+{
+    translate_match(match_, false, None, env, items)
+}
+
+/// Translates a match lowered from `if let` or `let else`, whose last arm is the synthesized else branch.
+pub fn translate_lowered_match_expr(
+    match_: &ExprMatch,
+    else_span: Span,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    translate_match(match_, false, Some(else_span), env, items)
+}
+
+/// Translates a match whose identifiers in patterns never capture variables, like the pattern of a for loop.
+pub fn translate_fresh_match_expr(
+    match_: &ExprMatch,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    translate_match(match_, true, None, env, items)
+}
+
+fn translate_match(
+    match_: &ExprMatch,
+    fresh_bindings: bool,
+    else_span: Option<Span>,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprMatch { expr, arms, .. } = match_;
+
+    // parse matched expression
+    let pattern = translate_expr(expr, env, items)?;
+
+    let match_traits = translate_match_arms(pattern, arms, fresh_bindings, else_span, env, items)?;
+    apply_match_traits(&match_traits, env)
+}
+
+fn translate_match_arms(
+    pattern: TypeVar,
+    arms: &[Arm],
+    fresh_bindings: bool,
+    else_span: Option<Span>,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<MatchTraits> {
+    // the arms after the first guarded arm are translated once into a match of their own,
+    // which is reached if the guard is false or no earlier arm matches
+    let (arms, fallthrough) = match arms.iter().position(|arm| arm.guard.is_some()) {
+        Some(index) => {
+            let rest_traits = translate_match_arms(
+                pattern.clone(),
+                &arms[(index + 1)..],
+                fresh_bindings,
+                else_span,
+                env,
+                items,
+            )?;
+
+            // the body of the fall-through arm is replaced by the rest match
+            let fallthrough_arm: Arm = syn::parse2(quote! { _ => {} })?;
+            let arms: Vec<_> = arms[..=index]
+                .iter()
+                .cloned()
+                .chain(iter::once(fallthrough_arm))
+                .collect();
+            (arms, Some(rest_traits))
+        }
+        None => (arms.to_owned(), None),
+    };
+
+    // save quantifiers (after matched expression)
+    let mutable_quantifiers = env.mutable_quantifiers();
+
+    // generate trait names
+    let match_trait_name = env
+        .register_trait_name(&format!("{}MatchArm_", IDENT_PREFIX))
+        .expect("the trait name cannot proper prefix of existing trait names");
+
+    let assign_trait_names: HashMap<_, _> = mutable_quantifiers
+        .keys()
+        .map(|ident| {
+            let trait_name = env
+                .register_trait_name(&format!("{}MatchAssign_", IDENT_PREFIX))
+                .expect("please report bug: accidentally using a proper prefix");
+            (ident, trait_name)
+        })
+        .collect();
+
+    // generate generics
+    let parent_free_quantifiers = env.free_quantifiers();
+    let parent_substitution: IndexMap<_, _> = parent_free_quantifiers
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(index, var)| (var, format_ident!("{}GENERIC_{}", IDENT_PREFIX, index)))
+        .collect();
+    let parent_generics: Vec<_> = parent_substitution.values().collect();
+
+    // generate traits
+    let (match_trait_item, assign_trait_items) = {
+        let cond_generic = format_ident!("{}_CONDITION_GENERIC", IDENT_PREFIX);
+
+        let match_trait_item: ItemTrait = syn::parse2(quote! {
+            #[allow(non_snake_case)]
+            pub trait #match_trait_name < #(#parent_generics,)* #cond_generic> {
+                type Output;
+            }
+        })?;
+
+        let assign_trait_items: Vec<ItemTrait> = assign_trait_names
+            .values()
+            .map(|trait_name| {
+                syn::parse2(quote! {
+                    #[allow(non_snake_case)]
+                    pub trait #trait_name < #(#parent_generics,)* #cond_generic> {
+                        type Output;
+                    }
+                })
+            })
+            .try_collect()?;
+
+        (match_trait_item, assign_trait_items)
+    };
+
+    // generate impl items
+    let impl_items = {
+        let mut impl_items = vec![];
+
+        // patterns of previous arms, which are excluded from later arms
+        let mut prev_patterns: Vec<(PatNode, bool)> = vec![];
+
+        for (
+            index,
+            Arm {
+                attrs,
+                pat,
+                guard,
+                body,
+                ..
+            },
+        ) in arms.iter().enumerate()
+        {
+            let is_fallthrough_arm = fallthrough.is_some() && index + 1 == arms.len();
+            // the synthesized else branch of `if let` and `let else`
+            let is_else_arm =
+                else_span.is_some() && fallthrough.is_none() && index + 1 == arms.len();
+            let mut branched_env = env.branch();
+
+            // parse attributes
+            let ArmAttributes {
+                generics_attr,
+                capture_attr,
+                default_attr,
+            } = unpack_pat_attr(attrs)?;
+
+            // insert new free quantifiers and predicates
+            let extra_free_quantifiers = match &generics_attr {
+                Some(GenericsAttr { params }) => {
+                    let free_quantifiers: IndexSet<_> = params
+                        .iter()
+                        .map(|param| -> syn::Result<_> {
+                            let SimpleTypeParam { ident, .. } = param;
+                            let var = branched_env.insert_free_quantifier(ident.to_owned());
+                            let predicate = param.parse_where_predicate_var(&mut branched_env)?;
+                            branched_env.insert_predicate(predicate);
+                            Ok(var)
+                        })
+                        .try_collect()?;
+                    free_quantifiers
+                }
+                None => IndexSet::new(),
+            };
+
+            // insert new predicates for captured variables
+            match &capture_attr {
+                Some(CaptureAttr { params }) => {
+                    for param in params.iter() {
+                        let predicate = param.parse_where_predicate_var(&mut branched_env)?;
+                        branched_env.insert_predicate(predicate);
+                    }
+                }
+                None => (),
+            }
+
+            // parse pattern
+            let (targets, bindings, alternative_bindings, implicit_captures) = {
+                // list in-place free and captured variables
+                let mut variables = HashMap::new();
+
+                if let Some(CaptureAttr { params }) = capture_attr {
+                    let vars: Vec<_> = params
+                        .iter()
+                        .map(|SimpleTypeParam { ident, .. }| {
+                            branched_env
+                                .get_variable(ident)
+                                .map(|var| (ident.to_owned(), var))
+                                .ok_or_else(|| {
+                                    Error::new(ident.span(), "the variable is not defined")
+                                })
+                        })
+                        .try_collect()?;
+                    variables.extend(vars);
+                };
+
+                if let Some(GenericsAttr { params }) = &generics_attr {
+                    let iter = params.iter().map(|SimpleTypeParam { ident, .. }| {
+                        branched_env
+                            .get_variable(ident)
+                            .map(|var| (ident.to_owned(), var))
+                            .unwrap()
+                    });
+                    variables.extend(iter);
+                };
+
+                // bindings and wildcards in the pattern become extra free quantifiers
+                let mut scope = parse_pattern::PatternScope::new(
+                    variables,
+                    extra_free_quantifiers,
+                    &mut branched_env,
+                );
+                scope.capture_implicitly = !fresh_bindings;
+
+                // each alternative of an or-pattern is parsed in the same scope
+                let alternatives: Vec<&Pat> = match pat {
+                    Pat::Or(PatOr { cases, .. }) => cases.iter().collect(),
+                    pat => vec![pat],
+                };
+                let targets: Vec<_> = alternatives
+                    .into_iter()
+                    .map(|pat| -> syn::Result<_> {
+                        scope.begin_alternative();
+                        let target = match pat {
+                            // a catch-all name binds a fresh generic, shadowing the variable of the same name
+                            Pat::Ident(_) => {
+                                let capture_implicitly =
+                                    mem::replace(&mut scope.capture_implicitly, false);
+                                let target =
+                                    parse_pattern::parse_type_pattern_from_pat(pat, &mut scope);
+                                scope.capture_implicitly = capture_implicitly;
+                                target?
+                            }
+                            pat => parse_pattern::parse_type_pattern_from_pat(pat, &mut scope)?,
+                        };
+                        Ok((pat, target))
+                    })
+                    .try_collect()?;
+
+                // alternatives must bind the same variables
+                let alternative_bindings: Vec<IndexSet<_>> = targets
+                    .iter()
+                    .map(|(_, target)| {
+                        PatNode::from_type_var(target, &scope.bindings).binding_vars()
+                    })
+                    .collect();
+                let named_bindings = |vars: &IndexSet<Shared<Variable>>| -> IndexSet<_> {
+                    vars.iter()
+                        .filter(|var| !scope.wildcards.contains(*var))
+                        .cloned()
+                        .collect()
+                };
+                let first = named_bindings(&alternative_bindings[0]);
+                for ((pat, _), vars) in targets.iter().zip(alternative_bindings.iter()).skip(1) {
+                    let vars = named_bindings(vars);
+                    if let Some(var) = first.symmetric_difference(&vars).next() {
+                        let name = scope.name_of(var).unwrap();
+                        return Err(Error::new(
+                            pat.span(),
+                            format!("variable `{}` is not bound in all patterns", name),
+                        ));
+                    }
+                }
+
+                (
+                    targets,
+                    scope.bindings,
+                    alternative_bindings,
+                    scope.implicit_captures,
+                )
+            };
+
+            // generate substitutions for free variables
+            let substitution: IndexMap<_, _> = branched_env
+                .free_quantifiers()
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(index, var)| (var, format_ident!("{}GENERIC_{}", IDENT_PREFIX, index)))
+                .collect();
+
+            // generate generic identifiers
+            let input_generics: Vec<_> = substitution
+                .iter()
+                .filter_map(|(var, generic)| {
+                    if bindings.contains(var) {
+                        None
+                    } else {
+                        Some(generic)
+                    }
+                })
+                .collect();
+
+            // parse body, where a guard falls through to the later arms if the condition is false
+            let body_value = match (guard, &fallthrough) {
+                (Some((_if, cond)), Some(rest_traits)) => {
+                    let then_branch: Block = syn::parse2(quote! { { #body } })?;
+                    let translate_else = |env: &mut Env, _items: &mut Vec<Item>| {
+                        apply_match_traits(rest_traits, env)
+                    };
+                    translate_if_else(
+                        cond,
+                        &then_branch,
+                        Some(translate_else),
+                        &mut branched_env,
+                        items,
+                    )?
+                }
+                (_, Some(rest_traits)) if is_fallthrough_arm => {
+                    apply_match_traits(rest_traits, &mut branched_env)?
+                }
+                _ => translate_expr(body, &mut branched_env, items)?,
+            }
+            .substitute(&branched_env, &substitution);
+            let predicates: Vec<_> = branched_env
+                .predicates()
+                .into_iter()
+                .map(|predicate| predicate.substitute(&branched_env, &substitution))
+                .collect();
+            let assign_values: Vec<_> = mutable_quantifiers
+                .keys()
+                .map(|ident| {
+                    let value = branched_env
+                        .get_variable(ident)
+                        .expect("please report bug: the variable is missing")
+                        .value
+                        .as_ref()
+                        .unwrap()
+                        .substitute(&branched_env, &substitution);
+                    (ident, value)
+                })
+                .collect();
+
+            // exclude types matched by previous arms and alternatives
+            let mut instances = vec![];
+            for ((pat, target), vars) in targets.iter().zip(alternative_bindings.iter()) {
+                let families = match else_span {
+                    Some(else_span) if is_else_arm => Families {
+                        registry: env.registry(),
+                        span: else_span,
+                        suggest_default: false,
+                    },
+                    _ => Families {
+                        registry: env.registry(),
+                        span: pat.span(),
+                        suggest_default: true,
+                    },
+                };
+                let pattern_node = PatNode::from_type_var(target, &bindings);
+
+                // a captured variable cannot be told apart from the types of previous arms
+                if let Some(ident) = implicit_captures.first() {
+                    let overlaps = prev_patterns
+                        .iter()
+                        .any(|(prev, _)| pattern_node.overlap(prev) != Overlap::No);
+                    if overlaps {
+                        return Err(Error::new(
+                            ident.span(),
+                            format!(
+                                "the pattern captures the variable `{}`, which may be matched by previous arms, bind a new name and compare it in an if guard instead",
+                                ident
+                            ),
+                        ));
+                    }
+                }
+
+                let rest = match default_attr {
+                    Some(()) => vec![pattern_node],
+                    None => {
+                        let rest = pattern_node
+                            .exclude(prev_patterns.iter().map(|(prev, _)| prev), &families)?;
+
+                        // types excluded by guarded arms are still reachable by fall-through
+                        let is_reachable = !rest.is_empty()
+                            || !pattern_node
+                                .exclude(
+                                    prev_patterns
+                                        .iter()
+                                        .filter(|(_, is_guarded)| !is_guarded)
+                                        .map(|(prev, _)| prev),
+                                    &families,
+                                )?
+                                .is_empty();
+                        if !is_reachable {
+                            // every type is matched before falling through
+                            if is_fallthrough_arm || is_else_arm {
+                                continue;
+                            }
+                            return Err(Error::new(
+                                pat.span(),
+                                "unreachable pattern, the types are matched by previous arms",
+                            ));
+                        }
+                        prev_patterns.push((pattern_node, guard.is_some()));
+                        rest
+                    }
+                };
+
+                // drop generics of bindings only used by other alternatives
+                let unused: HashSet<_> = alternative_bindings
+                    .iter()
+                    .flatten()
+                    .filter(|var| !vars.contains(*var))
+                    .map(|var| &substitution[var])
+                    .collect();
+                instances.extend(rest.into_iter().map(|node| {
+                    (
+                        node,
+                        families.span,
+                        families.suggest_default,
+                        unused.clone(),
+                    )
+                }));
+            }
+
+            // each part of the pattern becomes an impl sharing the body
+            for (instance, span, suggest_default, unused) in instances {
+                let families = Families {
+                    registry: env.registry(),
+                    span,
+                    suggest_default,
+                };
+                let mut rendered = Rendered::default();
+                let target =
+                    instance.render(&mut branched_env, &substitution, &families, &mut rendered)?;
+
+                let is_nonlinear = rendered.refined.iter().any(|var| {
+                    rendered.kept.contains(var)
+                        || rendered
+                            .refined
+                            .iter()
+                            .filter(|other| other == &var)
+                            .count()
+                            > 1
+                });
+                if is_nonlinear {
+                    return Err(families.error(
+                        "cannot exclude types matched by previous arms because the binding is used more than once",
+                    ));
+                }
+
+                let replacements = &rendered.replacements;
+                let all_generics: Vec<_> = substitution
+                    .values()
+                    .filter(|generic| {
+                        !replacements.contains_key(generic) && !unused.contains(generic)
+                    })
+                    .chain(rendered.generics.iter())
+                    .collect();
+                let input_generics: Vec<_> = input_generics
+                    .iter()
+                    .map(|generic| replace_idents(quote! { #generic }, replacements))
+                    .collect();
+                let predicates: Vec<_> = predicates
+                    .iter()
+                    .map(|predicate| replace_idents(quote! { #predicate }, replacements))
+                    .chain(rendered.predicates.iter().cloned())
+                    .collect();
+                let body_value = replace_idents(quote! { #body_value }, replacements);
+
+                // impl item for output type
+                let match_impl: ItemImpl = {
+                    let trait_ = quote!( #match_trait_name<#(#input_generics,)* #target> );
+                    let default_ = if let Some(()) = default_attr {
+                        quote! { default }
+                    } else {
+                        quote! {}
+                    };
+                    let impl_ = quote! {
+                        impl< #(#all_generics),* > #trait_ for ()
+                        where
+                            #(#predicates),*
+                        {
+                            #default_ type Output = #body_value;
+                        }
+                    };
+                    syn::parse2(impl_)?
+                };
+
+                // impls for variable assignments
+                let assign_impls: Vec<ItemImpl> = assign_values
+                    .iter()
+                    .map(|(ident, value)| {
+                        let trait_name = &assign_trait_names[ident];
+                        let value = replace_idents(quote! { #value }, replacements);
+                        let trait_ = quote! { #trait_name< #(#input_generics,)* #target > };
+                        let impl_ = quote! {
+                            impl< #(#all_generics),* > #trait_ for ()
+                            where
+                                #(#predicates),*
+                            {
+                                type Output = #value;
+                            }
+                        };
+                        syn::parse2(impl_)
+                    })
+                    .try_collect()?;
+
+                impl_items.push(match_impl);
+                impl_items.extend(assign_impls);
+            }
+        }
+
+        impl_items
+    };
+
+    // add items to env
+    items.push(Item::Trait(match_trait_item));
+    items.extend(assign_trait_items.into_iter().map(Item::Trait));
+    items.extend(impl_items.into_iter().map(Item::Impl));
+
+    Ok(MatchTraits {
+        match_trait_name,
+        assign_trait_names: mutable_quantifiers
+            .keys()
+            .map(|ident| (ident.to_owned(), assign_trait_names[ident].to_owned()))
+            .collect(),
+        parent_free_quantifiers,
+        pattern,
+    })
+}
+
+fn apply_match_traits(match_traits: &MatchTraits, env: &mut Env) -> syn::Result<TypeVar> {
+    let MatchTraits {
+        match_trait_name,
+        assign_trait_names,
+        parent_free_quantifiers,
+        pattern,
+    } = match_traits;
+
+    let args: Vec<_> = parent_free_quantifiers
+        .iter()
+        .map(|var| TypeVar::Var(var.clone()))
+        .chain(iter::once(pattern.clone()))
+        .collect();
+    let apply = |trait_name: &Ident, env: &mut Env| {
+        let trait_ = PathVar {
+            segments: vec![SegmentVar {
+                ident: trait_name.to_owned(),
+                arguments: PathArgumentsVar::AngleBracketed(args.clone()),
+            }],
+        };
+        let path = {
+            let mut path = trait_.clone();
+            path.segments.push(SegmentVar {
+                ident: format_ident!("Output"),
+                arguments: PathArgumentsVar::None,
+            });
+            path
+        };
+        let bounded_ty: TypeVar = syn::parse2::<Type>(quote! { () })
+            .unwrap()
+            .parse_pure_type(&mut vec![])
+            .unwrap();
+        let output = TypeVar::Path(TypePathVar {
+            qself: Some(QSelfVar {
+                ty: Box::new(bounded_ty.clone()),
+                position: trait_.segments.len(),
+            }),
+            path,
+        });
+        let predicate = WherePredicateVar::Type(PredicateTypeVar {
+            bounded_ty,
+            bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
+                modifier: TraitBoundModifierVar::None,
+                path: trait_,
+            })],
+        });
+
+        env.insert_predicate(predicate);
+        output
+    };
+
+    // assign affected variables
+    for (ident, trait_name) in assign_trait_names.iter() {
+        let value = apply(trait_name, env);
+        env.assign_quantifier(ident, value)?;
+    }
+
+    // construct returned value
+    Ok(apply(match_trait_name, env))
+}
+
+fn unpack_pat_attr(attrs: &[Attribute]) -> syn::Result<ArmAttributes> {
+    let mut generics_attr = None;
+    let mut capture_attr = None;
+    let mut default_attr = None;
+
+    // check attributes one by one
+    for attr in attrs.iter() {
+        let Attribute { style, path, .. } = attr;
+
+        // sanity check
+        match (style, path.get_ident()) {
+            (AttrStyle::Outer, Some(ident)) => match ident.to_string().as_str() {
+                "generics" => match generics_attr {
+                    Some(_) => {
+                        return Err(Error::new(
+                            path.span(),
+                            "the generics attribute is defined more than once",
+                        ));
+                    }
+                    None => generics_attr = Some(attr),
+                },
+                "capture" => match capture_attr {
+                    Some(_) => {
+                        return Err(Error::new(
+                            path.span(),
+                            "the capture attribute is defined more than once",
+                        ));
+                    }
+                    None => capture_attr = Some(attr),
+                },
+                "default" => match default_attr {
+                    Some(_) => {
+                        return Err(Error::new(
+                            path.span(),
+                            "the default attribute is defined more than once",
+                        ));
+                    }
+                    None => default_attr = Some(()),
+                },
+                _ => return Err(Error::new(path.span(), "unsupported attribute")),
+            },
+            (AttrStyle::Outer, None) => {
+                return Err(Error::new(path.span(), "unsupported attribute"));
+            }
+            (AttrStyle::Inner(_), _) => {
+                return Err(Error::new(attr.span(), "inner attribute is not supported"))
+            }
+        }
+    }
+
+    // parse
+    let generics_attr: Option<GenericsAttr> = generics_attr
+        .map(|attr| syn::parse2(attr.tokens.to_owned()))
+        .transpose()?;
+    let capture_attr: Option<CaptureAttr> = capture_attr
+        .map(|attr| syn::parse2(attr.tokens.to_owned()))
+        .transpose()?;
+
+    // sanity check
+    match (&generics_attr, &capture_attr) {
+        (Some(generics_attr), Some(capture_attr)) => {
+            let generic_idents: HashSet<_> = generics_attr
+                .params
+                .iter()
+                .map(|param| &param.ident)
+                .collect();
+            for SimpleTypeParam {
+                ident: capture_ident,
+                ..
+            } in capture_attr.params.iter()
+            {
+                if let Some(generic_ident) = generic_idents.get(capture_ident) {
+                    let mut err = Error::new(
+                        capture_ident.span(),
+                        "cannot capture a variable already in generics list",
+                    );
+                    err.combine(Error::new(
+                        generic_ident.span(),
+                        "the name is declared as a generic here",
+                    ));
+                    return Err(err);
+                }
+            }
+        }
+        _ => (),
+    }
+
+    Ok(ArmAttributes {
+        generics_attr,
+        capture_attr,
+        default_attr,
+    })
+}
+
+mod parse_pattern {
+    use super::*;
+
+    pub struct PatternScope<'a> {
+        pub captured: HashMap<Ident, Shared<Variable>>,
+        pub bindings: IndexSet<Shared<Variable>>,
+        pub wildcards: IndexSet<Shared<Variable>>,
+        // bindings by name, shared by alternatives of an or-pattern
+        pub named: HashMap<Ident, Shared<Variable>>,
+        alternative: HashSet<Ident>,
+        pub capture_implicitly: bool,
+        // the lowercase identifiers referring to variables in scope
+        pub implicit_captures: Vec<Ident>,
+        pub env: &'a mut Env,
+    }
+
+    impl<'a> PatternScope<'a> {
+        pub fn new(
+            captured: HashMap<Ident, Shared<Variable>>,
+            bindings: IndexSet<Shared<Variable>>,
+            env: &'a mut Env,
+        ) -> Self {
+            Self {
+                captured,
+                bindings,
+                wildcards: IndexSet::new(),
+                named: HashMap::new(),
+                alternative: HashSet::new(),
+                capture_implicitly: true,
+                implicit_captures: vec![],
+                env,
+            }
+        }
+
+        pub fn begin_alternative(&mut self) {
+            self.alternative.clear();
+        }
+
+        pub fn name_of(&self, var: &Shared<Variable>) -> Option<&Ident> {
+            self.named
+                .iter()
+                .chain(self.captured.iter())
+                .find(|(_, other)| *other == var)
+                .map(|(ident, _)| ident)
+        }
+
+        fn insert_binding(&mut self, ident: Ident) -> Shared<Variable> {
+            let var = self.env.insert_free_quantifier(ident);
+            self.bindings.insert(var.clone());
+            var
+        }
+
+        fn insert_wildcard(&mut self) -> Shared<Variable> {
+            let ident = format_ident!("{}WILDCARD_{}", IDENT_PREFIX, self.bindings.len());
+            let var = self.insert_binding(ident);
+            self.wildcards.insert(var.clone());
+            var
+        }
+    }
+
+    pub fn parse_type_pattern_from_pat(
+        pat: &Pat,
+        scope: &mut PatternScope,
+    ) -> syn::Result<TypeVar> {
+        match pat {
+            Pat::Ident(pat_ident) => {
+                // sanity check
+                let PatIdent {
+                    ident,
+                    by_ref,
+                    mutability,
+                    subpat,
+                    ..
+                } = pat_ident;
+
+                if let Some(by_ref) = by_ref {
+                    return Err(Error::new(by_ref.span(), "ref keyword is not supported"));
+                }
+
+                if let Some(mutability) = mutability {
+                    return Err(Error::new(
+                        mutability.span(),
+                        "mut keyword is not supported",
+                    ));
+                }
+
+                if let Some(_) = subpat {
+                    return Err(Error::new(pat_ident.span(), "subpattern is not supported"));
+                }
+
+                parse_type_pattern_from_ident(ident, scope)
+            }
+            Pat::Wild(_) => {
+                let var = scope.insert_wildcard();
+                Ok(TypeVar::Var(var))
+            }
+            Pat::Path(PatPath { qself, path, .. }) => {
+                let qself = match qself {
+                    Some(QSelf { ty, position, .. }) => {
+                        let ty = parse_type_pattern(ty, scope)?;
+                        Some(QSelfVar {
+                            ty: Box::new(ty),
+                            position: rebase_qself_position(path, *position),
+                        })
+                    }
+                    None => None,
+                };
+                let path = parse_path_pattern(path, scope)?;
+                Ok(TypeVar::Path(TypePathVar { qself, path }))
+            }
+            Pat::Tuple(PatTuple { elems, .. }) => {
+                let elems: Vec<_> = elems
+                    .iter()
+                    .map(|elem| parse_type_pattern_from_pat(elem, scope))
+                    .try_collect()?;
+                Ok(TypeVar::Tuple(TypeTupleVar { elems }))
+            }
+            Pat::Lit(PatLit { expr, .. }) => match &**expr {
+                Expr::Lit(ExprLit { lit, .. }) => translate_lit(lit, false),
+                Expr::Unary(ExprUnary {
+                    op: UnOp::Neg(_),
+                    expr,
+                    ..
+                }) => match &**expr {
+                    Expr::Lit(ExprLit { lit, .. }) => translate_lit(lit, true),
+                    _ => Err(Error::new(expr.span(), "not a literal")),
+                },
+                _ => Err(Error::new(expr.span(), "not a literal")),
+            },
+            Pat::TupleStruct(PatTupleStruct {
+                path,
+                pat: PatTuple { elems, .. },
+                ..
+            }) => {
+                // positional fields, `..` stands for the remaining fields
+                let has_rest = elems.iter().any(|elem| matches!(elem, Pat::Rest(_)));
+                let n_pats = elems.len() - has_rest as usize;
+                let n_fields = match (has_rest, record_fields(path, scope)) {
+                    (_, Ok(members)) => members.len(),
+                    (false, Err(_)) => n_pats,
+                    (true, Err(err)) => return Err(err),
+                };
+                if n_pats > n_fields || (!has_rest && n_pats != n_fields) {
+                    return Err(Error::new(
+                        elems.span(),
+                        format!(
+                            "this pattern has {} fields, but the struct has {} fields",
+                            n_pats, n_fields
+                        ),
+                    ));
+                }
+
+                let mut args = vec![];
+                for elem in elems.iter() {
+                    match elem {
+                        Pat::Rest(_) => {
+                            for _ in 0..(n_fields - n_pats) {
+                                args.push(TypeVar::Var(scope.insert_wildcard()));
+                            }
+                        }
+                        elem => args.push(parse_type_pattern_from_pat(elem, scope)?),
+                    }
+                }
+                parse_record_pattern(path, args, scope)
+            }
+            Pat::Struct(PatStruct {
+                path,
+                fields,
+                dot2_token,
+                ..
+            }) => {
+                // match field patterns to declared fields
+                let members = record_fields(path, scope)?;
+                let mut field_pats = HashMap::new();
+                for FieldPat { member, pat, .. } in fields.iter() {
+                    if !members.contains(member) {
+                        return Err(Error::new(member.span(), "no such field in the struct"));
+                    }
+                    if field_pats.insert(member.to_owned(), &**pat).is_some() {
+                        return Err(Error::new(
+                            member.span(),
+                            "the field is specified more than once",
+                        ));
+                    }
+                }
+
+                if dot2_token.is_none() {
+                    let missing: Vec<_> = members
+                        .iter()
+                        .filter(|member| !field_pats.contains_key(member))
+                        .map(|member| format!("`{}`", quote! { #member }))
+                        .collect();
+                    if !missing.is_empty() {
+                        return Err(Error::new(
+                            path.span(),
+                            format!(
+                                "missing fields {}, or ignore them by `..`",
+                                missing.join(", ")
+                            ),
+                        ));
+                    }
+                }
+
+                // place field patterns in declaration order
+                let args: Vec<_> = members
+                    .iter()
+                    .map(|member| match field_pats.get(member) {
+                        Some(pat) => parse_type_pattern_from_pat(pat, scope),
+                        None => Ok(TypeVar::Var(scope.insert_wildcard())),
+                    })
+                    .try_collect()?;
+                parse_record_pattern(path, args, scope)
+            }
+            Pat::Or(_) => Err(Error::new(
+                pat.span(),
+                "or-pattern is only supported at the top level of a match arm",
+            )),
+            _ => Err(Error::new(pat.span(), "not a type")),
+        }
+    }
+
+    pub fn parse_type_pattern(type_: &Type, scope: &mut PatternScope) -> syn::Result<TypeVar> {
+        let ty = match type_ {
+            Type::Path(TypePath { qself, path }) => match (qself, path.get_ident()) {
+                (Some(QSelf { ty, position, .. }), _) => {
+                    let ty = parse_type_pattern(ty, scope)?;
+                    let position = rebase_qself_position(path, *position);
+                    let path = parse_path_pattern(path, scope)?;
+                    TypeVar::Path(TypePathVar {
+                        qself: Some(QSelfVar {
+                            ty: Box::new(ty),
+                            position,
+                        }),
+                        path,
+                    })
+                }
+                (None, Some(ident)) => parse_type_pattern_from_ident(ident, scope)?,
+                (None, None) => {
+                    let path = parse_path_pattern(path, scope)?;
+                    TypeVar::Path(TypePathVar { qself: None, path })
+                }
+            },
+            Type::Tuple(TypeTuple { elems, .. }) => {
+                let elems: Vec<_> = elems
+                    .iter()
+                    .map(|elem| parse_type_pattern(elem, scope))
+                    .try_collect()?;
+                TypeVar::Tuple(TypeTupleVar { elems })
+            }
+            Type::Paren(TypeParen { elem, .. }) => parse_type_pattern(elem, scope)?,
+            Type::Infer(_) => TypeVar::Var(scope.insert_wildcard()),
+            _ => return Err(Error::new(type_.span(), "unsupported type variant")),
+        };
+        Ok(ty)
+    }
+
+    pub fn parse_type_pattern_from_ident(
+        ident: &Ident,
+        scope: &mut PatternScope,
+    ) -> syn::Result<TypeVar> {
+        if let Some(var) = scope.captured.get(ident) {
+            return Ok(TypeVar::Var(var.to_owned()));
+        }
+
+        // a lowercase identifier binds the matched type, or captures the variable if defined
+        if is_binding_ident(ident) {
+            if let Some(var) = scope.named.get(ident).cloned() {
+                if !scope.alternative.insert(ident.to_owned()) {
+                    return Err(Error::new(
+                        ident.span(),
+                        "identifier is bound more than once in the same pattern",
+                    ));
+                }
+                return Ok(TypeVar::Var(var));
+            }
+            if scope.capture_implicitly {
+                if let Some(var) = scope.env.get_variable(ident) {
+                    scope.implicit_captures.push(ident.to_owned());
+                    return Ok(TypeVar::Var(var));
+                }
+            }
+
+            scope.alternative.insert(ident.to_owned());
+            let var = scope.insert_binding(ident.to_owned());
+            scope.named.insert(ident.to_owned(), var.clone());
+            return Ok(TypeVar::Var(var));
+        }
+
+        Ok(TypeVar::Path(TypePathVar {
+            qself: None,
+            path: PathVar {
+                segments: vec![SegmentVar {
+                    ident: ident.to_owned(),
+                    arguments: PathArgumentsVar::None,
+                }],
+            },
+        }))
+    }
+
+    fn record_fields(path: &Path, scope: &PatternScope) -> syn::Result<Vec<Member>> {
+        let ident = &path.segments.last().unwrap().ident;
+        match scope.env.registry().record(ident) {
+            Some(record) => Ok(record.fields.clone()),
+            None => Err(Error::new(
+                path.span(),
+                "the struct is not declared in this typ! block",
+            )),
+        }
+    }
+
+    fn parse_record_pattern(
+        path: &Path,
+        args: Vec<TypeVar>,
+        scope: &mut PatternScope,
+    ) -> syn::Result<TypeVar> {
+        let mut path_var = parse_path_pattern(path, scope)?;
+        let last = path_var.segments.last_mut().unwrap();
+        if let PathArgumentsVar::None = last.arguments {
+            if !args.is_empty() {
+                last.arguments = PathArgumentsVar::AngleBracketed(args);
+            }
+        } else {
+            return Err(Error::new(
+                path.span(),
+                "type parameters are not allowed in struct pattern",
+            ));
+        }
+        Ok(TypeVar::Path(TypePathVar {
+            qself: None,
+            path: path_var,
+        }))
+    }
+
+    pub fn parse_path_pattern(path: &Path, scope: &mut PatternScope) -> syn::Result<PathVar> {
+        let Path { segments, .. } = path;
+        let mut segments: Vec<_> = segments
+            .iter()
+            .map(|segment| parse_segment_pattern(segment, scope))
+            .try_collect()?;
+        rebase_relative_path(&mut segments);
+        Ok(PathVar { segments })
+    }
+
+    pub fn parse_segment_pattern(
+        segment: &PathSegment,
+        scope: &mut PatternScope,
+    ) -> syn::Result<SegmentVar> {
+        let PathSegment { ident, arguments } = segment;
+        let arguments = match arguments {
+            PathArguments::None => PathArgumentsVar::None,
+            PathArguments::AngleBracketed(args) => {
+                let args = args
+                    .args
+                    .iter()
+                    .map(|arg| match arg {
+                        GenericArgument::Type(ty) => parse_type_pattern(ty, scope),
+                        GenericArgument::Constraint(Constraint { ident, bounds, .. }) => {
+                            // inline trait bounds on a binding
+                            if !is_binding_ident(ident) {
+                                return Err(Error::new(
+                                    ident.span(),
+                                    "trait bounds can only be put on lowercase bindings",
+                                ));
+                            }
+                            let ty = parse_type_pattern_from_ident(ident, scope)?;
+                            let bounds = bounds
+                                .iter()
+                                .map(|bound| bound.parse_type_param_bound_var(scope.env))
+                                .try_collect()?;
+                            scope
+                                .env
+                                .insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
+                                    bounded_ty: ty.clone(),
+                                    bounds,
+                                }));
+                            Ok(ty)
+                        }
+                        _ => Err(Error::new(arg.span(), "unsupported generic variant")),
+                    })
+                    .try_collect()?;
+                PathArgumentsVar::AngleBracketed(args)
+            }
+            PathArguments::Parenthesized(args) => {
+                let inputs = args
+                    .inputs
+                    .iter()
+                    .map(|ty| parse_type_pattern(ty, scope))
+                    .try_collect()?;
+                PathArgumentsVar::Parenthesized(inputs)
+            }
+        };
+
+        Ok(SegmentVar {
+            ident: ident.to_owned(),
+            arguments,
+        })
+    }
+
+    fn is_binding_ident(ident: &Ident) -> bool {
+        const PRIMITIVES: &[&str] = &[
+            "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32",
+            "i64", "i128", "isize", "f32", "f64",
+        ];
+
+        let name = ident.to_string();
+        !PRIMITIVES.contains(&name.as_str())
+            && name
+                .chars()
+                .next()
+                .is_some_and(|first| first.is_lowercase() || first == '_')
+    }
+}
@@ -0,0 +1,464 @@
+use super::*;
+
+pub fn translate_expr(expr: &Expr, scope: &mut Env, items: &mut Vec<Item>) -> syn::Result<TypeVar>
+where
+{
+    let ret = match expr {
+        Expr::Match(match_) => translate_match_expr(match_, scope, items),
+        Expr::Path(path) => translate_path_expr(path, scope, items),
+        Expr::Tuple(tuple) => translate_tuple_expr(tuple, scope, items),
+        Expr::Binary(binop) => translate_binary_expr(binop, scope, items),
+        Expr::If(if_) => translate_if_expr(if_, scope, items),
+        Expr::Block(block) => translate_block_expr(block, scope, items),
+        Expr::Call(call) => translate_call_expr(call, scope, items),
+        Expr::Paren(paren) => translate_expr(&paren.expr, scope, items),
+        Expr::Assign(assign) => translate_assign_expr(assign, scope, items),
+        Expr::Lit(lit) => translate_lit_expr(lit, scope, items),
+        Expr::Unary(unary) => translate_unary_expr(unary, scope, items),
+        Expr::Index(index) => translate_index_expr(index, scope, items),
+        Expr::MethodCall(call) => translate_method_call_expr(call, scope, items),
+        Expr::Field(field) => translate_field_expr(field, scope, items),
+        Expr::Struct(struct_) => translate_struct_expr(struct_, scope, items),
+        Expr::While(while_) => translate_while_expr(while_, scope, items),
+        Expr::ForLoop(for_) => translate_for_expr(for_, scope, items),
+        Expr::Loop(loop_) => translate_loop_expr(loop_, scope, items),
+        Expr::Closure(closure) => translate_closure_expr(closure, scope, items),
+        Expr::Return(return_) => translate_return_expr(return_, scope, items),
+        Expr::Break(break_) => translate_break_expr(break_, scope, items),
+        Expr::Continue(continue_) => translate_continue_expr(continue_, scope, items),
+        _ => Err(Error::new(expr.span(), "unsupported expression")),
+    };
+    ret
+}
+
+pub fn translate_method_call_expr(
+    call: &ExprMethodCall,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprMethodCall {
+        receiver,
+        method,
+        turbofish,
+        args,
+        ..
+    } = call;
+
+    // partially apply an operator value, unless a method named curry is declared
+    let is_curry_method = env
+        .registry()
+        .operators(method)
+        .iter()
+        .any(|operator| operator.has_receiver);
+    if method == "curry" && !is_curry_method {
+        if let Some(turbofish) = turbofish {
+            return Err(Error::new(turbofish.span(), "turbofish is not supported"));
+        }
+        return translate_curry_expr(call, env, items);
+    }
+
+    // translate to types, where turbofish types precede the arguments
+    let receiver_ty = translate_expr(&**receiver, env, items)?;
+    let turbofish_tys: Vec<_> = match turbofish {
+        Some(turbofish) => turbofish
+            .args
+            .iter()
+            .map(|arg| match arg {
+                GenericMethodArgument::Type(ty) => ty.parse_type_var(env),
+                GenericMethodArgument::Const(expr) => {
+                    Err(Error::new(expr.span(), "const argument is not supported"))
+                }
+            })
+            .try_collect()?,
+        None => vec![],
+    };
+    let arg_tys: Vec<_> = args
+        .iter()
+        .map(|arg| translate_expr(arg, env, items))
+        .try_collect()?;
+    let trait_ = resolve_method_trait(method, turbofish_tys, arg_tys, env)?;
+    let path = {
+        let mut path = trait_.clone();
+        path.segments.push(SegmentVar {
+            ident: format_ident!("Output"),
+            arguments: PathArgumentsVar::None,
+        });
+        path
+    };
+
+    let output = TypeVar::Path(TypePathVar {
+        qself: Some(QSelfVar {
+            ty: Box::new(receiver_ty.clone()),
+            position: trait_.segments.len(),
+        }),
+        path,
+    });
+
+    let predicate = WherePredicateVar::Type(PredicateTypeVar {
+        bounded_ty: receiver_ty,
+        bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
+            modifier: TraitBoundModifierVar::None,
+            path: trait_,
+        })],
+    });
+
+    env.insert_predicate(predicate);
+
+    Ok(output)
+}
+
+// methods defined in the typ! block are preferred, otherwise the method names a trait in scope
+fn resolve_method_trait(
+    method: &Ident,
+    turbofish_tys: Vec<TypeVar>,
+    arg_tys: Vec<TypeVar>,
+    env: &mut Env,
+) -> syn::Result<PathVar> {
+    let (methods, functions): (Vec<_>, Vec<_>) = env
+        .registry()
+        .operators(method)
+        .into_iter()
+        .partition(|operator| operator.has_receiver);
+
+    let trait_path = match methods.split_first() {
+        Some((operator, others)) => {
+            // sanity check
+            if let Some(other) = others
+                .iter()
+                .find(|other| other.trait_path != operator.trait_path)
+            {
+                let [trait_path, other_path] =
+                    [&operator.trait_path, &other.trait_path].map(|path| {
+                        path.segments
+                            .iter()
+                            .map(|segment| segment.ident.to_string())
+                            .join("::")
+                    });
+                return Err(Error::new(
+                    method.span(),
+                    format!(
+                        "the method is ambiguous, candidates are `{}` and `{}`",
+                        trait_path, other_path
+                    ),
+                ));
+            }
+            if operator.num_explicit_generics != turbofish_tys.len() {
+                return Err(Error::new(
+                    method.span(),
+                    count_mismatch(
+                        "method",
+                        "explicit generic",
+                        operator.num_explicit_generics,
+                        turbofish_tys.len(),
+                    ),
+                ));
+            }
+            if operator.num_args != arg_tys.len() {
+                return Err(Error::new(
+                    method.span(),
+                    count_mismatch("method", "argument", operator.num_args, arg_tys.len()),
+                ));
+            }
+            operator.trait_path.to_owned()
+        }
+        None => {
+            if !functions.is_empty() {
+                return Err(Error::new(
+                    method.span(),
+                    format!(
+                        "`{}` is an operator without self receiver, call it as `{}(...)` instead",
+                        method, method
+                    ),
+                ));
+            }
+
+            // fall back to the external trait of the same name, where the imports of enclosing
+            // modules are reached through super
+            env.registry()
+                .import(method)
+                .unwrap_or_else(|| Path::from(method.to_owned()))
+        }
+    };
+
+    let mut trait_ = trait_path.parse_path_var(env)?;
+    trait_.segments.last_mut().unwrap().arguments =
+        PathArgumentsVar::AngleBracketed(turbofish_tys.into_iter().chain(arg_tys).collect());
+    Ok(trait_)
+}
+
+pub fn translate_index_expr(
+    expr: &ExprIndex,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar>
+where
+{
+    let ExprIndex { expr, index, .. } = expr;
+    let operand = translate_expr(expr, env, items)?;
+    let index = translate_expr(index, env, items)?;
+
+    let trait_ = {
+        let mut path: PathVar = syn::parse2::<Path>(quote! { core::ops::Index })
+            .unwrap()
+            .parse_pure_path(&mut vec![])
+            .unwrap();
+        path.segments.last_mut().unwrap().arguments = PathArgumentsVar::AngleBracketed(vec![index]);
+        path
+    };
+    let path = {
+        let mut path = trait_.clone();
+        path.segments.push(SegmentVar {
+            ident: format_ident!("Output"),
+            arguments: PathArgumentsVar::None,
+        });
+        path
+    };
+    let output = TypeVar::Path(TypePathVar {
+        qself: Some(QSelfVar {
+            ty: Box::new(operand.clone()),
+            position: trait_.segments.len(),
+        }),
+        path,
+    });
+    let predicate = WherePredicateVar::Type(PredicateTypeVar {
+        bounded_ty: operand,
+        bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
+            modifier: TraitBoundModifierVar::None,
+            path: trait_,
+        })],
+    });
+
+    env.insert_predicate(predicate);
+    Ok(output)
+}
+
+pub fn translate_path_expr(
+    expr: &ExprPath,
+    scope: &mut Env,
+    _items: &mut Vec<Item>,
+) -> syn::Result<TypeVar>
+where
+{
+    // the name of an operator without self receiver is its marker value
+    if let ExprPath {
+        qself: None, path, ..
+    } = expr
+    {
+        if let Some(ident) = path.get_ident() {
+            let operator = scope
+                .registry()
+                .operators(ident)
+                .into_iter()
+                .find(|operator| !operator.has_receiver);
+            if let (None, Some(operator)) = (scope.get_variable(ident), operator) {
+                // the marker is exported next to the operator trait
+                let marker_path = {
+                    let mut marker_path = operator.trait_path;
+                    marker_path.segments.last_mut().unwrap().ident = format_ident!("{}Fn", ident);
+                    marker_path
+                };
+                let path = marker_path.parse_path_var(scope)?;
+                return Ok(TypeVar::Path(TypePathVar { qself: None, path }));
+            }
+        }
+    }
+
+    expr.parse_type_var(scope)
+}
+
+// the error message of a call given a wrong number of generics or arguments
+pub fn count_mismatch(callee: &str, noun: &str, expected: usize, given: usize) -> String {
+    format!(
+        "this {} takes {} {}{}, but {} {} given",
+        callee,
+        expected,
+        noun,
+        if expected == 1 { "" } else { "s" },
+        given,
+        if given == 1 { "is" } else { "are" }
+    )
+}
+
+// check the number of explicit generics and arguments against the operator in the typ! block
+fn check_operator_call(path: &Path, num_args: usize, scope: &Env) -> syn::Result<()> {
+    let segment = match path.segments.iter().collect::<Vec<_>>().as_slice() {
+        [segment] if path.leading_colon.is_none() => *segment,
+        _ => return Ok(()),
+    };
+    let operator = match scope
+        .registry()
+        .operators(&segment.ident)
+        .into_iter()
+        .find(|operator| !operator.has_receiver)
+    {
+        Some(operator) => operator,
+        None => return Ok(()),
+    };
+
+    let num_generics = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.len(),
+        _ => 0,
+    };
+    if operator.num_explicit_generics != num_generics {
+        return Err(Error::new(
+            segment.span(),
+            count_mismatch(
+                "operator",
+                "explicit generic",
+                operator.num_explicit_generics,
+                num_generics,
+            ),
+        ));
+    }
+    if operator.num_args != num_args {
+        return Err(Error::new(
+            segment.span(),
+            count_mismatch("operator", "argument", operator.num_args, num_args),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn translate_tuple_expr(
+    tuple: &ExprTuple,
+    scope: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar>
+where
+{
+    // translate each element
+    let elems: Vec<_> = tuple
+        .elems
+        .iter()
+        .map(|expr| translate_expr(expr, scope, items))
+        .try_collect()?;
+
+    // merge tokens from each element
+    Ok(TypeVar::Tuple(TypeTupleVar { elems }))
+}
+
+pub fn translate_block_expr(
+    block: &ExprBlock,
+    scope: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    translate_block(&block.block, scope, items)
+}
+
+pub fn translate_call_expr(
+    call: &ExprCall,
+    scope: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar>
+where
+{
+    let ExprCall { func, args, .. } = call;
+
+    // compose two operator values, unless the name is bound to a variable or an operator
+    if let Expr::Path(ExprPath {
+        qself: None, path, ..
+    }) = &**func
+    {
+        let compose = format_ident!("compose");
+        if path.is_ident(&compose)
+            && scope.get_variable(&compose).is_none()
+            && scope.registry().operators(&compose).is_empty()
+        {
+            return translate_compose_expr(call, scope, items);
+        }
+    }
+
+    // parse arguments
+    let args: Vec<_> = args
+        .iter()
+        .map(|arg| translate_expr(arg, scope, items))
+        .try_collect()?;
+
+    // call an operator value bound to a variable, or a closure expression
+    let callee = match &**func {
+        Expr::Path(ExprPath {
+            qself: None, path, ..
+        }) => path
+            .get_ident()
+            .and_then(|ident| scope.get_variable(ident))
+            .map(TypeVar::Var),
+        Expr::Path(_) => None,
+        func => Some(translate_expr(func, scope, items)?),
+    };
+    if let Some(callee) = callee {
+        return translate_value_call(&callee, args, func.span(), scope);
+    }
+
+    // parse the function path to a trait path
+    let trait_path = {
+        let path = match &**func {
+            Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) => path,
+            _ => return Err(Error::new(func.span(), "not a trait")),
+        };
+        check_operator_call(path, args.len(), scope)?;
+        let mut trait_path = path.parse_path_var(scope)?;
+
+        // set the type arguments on trait, where explicit generics precede the arguments
+        let last = trait_path.segments.last_mut().unwrap();
+        last.arguments = match mem::replace(&mut last.arguments, PathArgumentsVar::None) {
+            PathArgumentsVar::None => PathArgumentsVar::AngleBracketed(args),
+            PathArgumentsVar::AngleBracketed(mut generics) => {
+                generics.extend(args);
+                PathArgumentsVar::AngleBracketed(generics)
+            }
+            PathArgumentsVar::Parenthesized(_) => {
+                return Err(Error::new(
+                    func.span(),
+                    "parenthesized arguments are not allowed in trait call",
+                ))
+            }
+        };
+
+        trait_path
+    };
+
+    // insert predicates
+    let (output, predicate) = {
+        // construct the output type
+        let bounded_ty: TypeVar = syn::parse2::<Type>(quote! { () })
+            .unwrap()
+            .parse_pure_type(&mut vec![])
+            .unwrap();
+        let output = {
+            let position = trait_path.segments.len();
+            let path = {
+                let mut path = trait_path.clone();
+                path.segments.push(SegmentVar {
+                    ident: format_ident!("Output"),
+                    arguments: PathArgumentsVar::None,
+                });
+                path
+            };
+            TypeVar::Path(TypePathVar {
+                qself: Some(QSelfVar {
+                    ty: Box::new(bounded_ty.clone()),
+                    position,
+                }),
+                path,
+            })
+        };
+
+        // construct the predicate
+        let predicate = WherePredicateVar::Type(PredicateTypeVar {
+            bounded_ty,
+            bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
+                modifier: TraitBoundModifierVar::None,
+                path: trait_path,
+            })],
+        });
+
+        (output, predicate)
+    };
+
+    scope.insert_predicate(predicate);
+
+    Ok(output)
+}
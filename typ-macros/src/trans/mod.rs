@@ -0,0 +1,134 @@
+mod alias;
+mod assign;
+mod binop;
+mod block;
+mod closure;
+mod compose;
+mod enum_;
+mod fn_;
+mod if_;
+mod impl_;
+mod lit;
+mod loop_;
+mod match_;
+mod misc;
+mod pattern;
+mod return_;
+mod struct_;
+mod trait_;
+mod unary;
+
+pub use crate::{
+    common::*,
+    env::{Closure, Env, Variable},
+    parse::{CaptureAttr, GenericsAttr, ItemTypeExpr, LocalElse, SimpleTypeParam},
+    registry::{explicit_generics, RecordDecl, Registry},
+    utils::Shared,
+    var::{
+        rebase_qself_position, rebase_relative_path, ParsePathVar, ParsePurePath, ParsePureType,
+        ParsePureWherePredicate, ParseTypeParamBoundVar, ParseTypeParamBoundsVar, ParseTypeVar,
+        ParseWherePredicateVar, PathArgumentsVar, PathVar, PredicateTypeVar, QSelfVar, SegmentVar,
+        Subsitution, TraitBoundModifierVar, TraitBoundVar, TypeParamBoundVar, TypePathVar,
+        TypeTupleVar, TypeVar, WherePredicateVar,
+    },
+};
+pub use alias::*;
+pub use assign::*;
+pub use binop::*;
+pub use block::*;
+pub use closure::*;
+pub use compose::*;
+pub use enum_::*;
+pub use fn_::*;
+pub use if_::*;
+pub use impl_::*;
+pub use lit::*;
+pub use loop_::*;
+pub use match_::*;
+pub use misc::*;
+pub use pattern::*;
+pub use return_::*;
+pub use struct_::*;
+pub use trait_::*;
+pub use unary::*;
+
+pub fn translate_items(items: &[Item], parent: Option<&Rc<Registry>>) -> syn::Result<TokenStream> {
+    // collect declarations visible to all items
+    let registry = Rc::new(Registry::new(items, parent.cloned()));
+
+    let tokens_vec: Vec<_> = items
+        .into_iter()
+        .map(|item| {
+            let tokens = match item {
+                Item::Enum(enum_) => translate_enum(&enum_)?,
+                Item::Fn(fn_) => {
+                    let ItemFn {
+                        sig, block, vis, ..
+                    } = fn_;
+                    translate_fn(vis, sig, block, None, None, None, &registry)?
+                }
+                Item::Struct(struct_) => translate_struct(&struct_)?,
+                Item::Impl(impl_) => translate_impl(&impl_, &registry)?,
+                Item::Trait(trait_) => translate_trait(trait_, &registry)?,
+                Item::Mod(mod_) => translate_mod(mod_, &registry)?,
+                Item::Const(const_) => translate_const(const_, &registry)?,
+                Item::Verbatim(tokens) => {
+                    let item: ItemTypeExpr = syn::parse2(tokens.clone())?;
+                    translate_type_alias(&item, &registry)?
+                }
+                Item::Use(use_) => translate_use(&use_),
+                Item::Type(type_) => quote! {
+                    #[allow(non_camel_case_types)]
+                    #type_
+                },
+                _ => {
+                    return Err(Error::new(item.span(), "unsupported item kind"));
+                }
+            };
+            Ok(tokens)
+        })
+        .try_collect()?;
+
+    let expanded = quote! {
+        #(#tokens_vec)*
+    };
+
+    Ok(expanded)
+}
+
+fn translate_mod(mod_: &ItemMod, registry: &Rc<Registry>) -> syn::Result<TokenStream> {
+    let ItemMod {
+        attrs,
+        vis,
+        ident,
+        content,
+        ..
+    } = mod_;
+
+    let items = match content {
+        Some((_, items)) => items,
+        None => {
+            return Err(Error::new(
+                mod_.span(),
+                "module without body is not supported",
+            ))
+        }
+    };
+
+    // the items in module see the declarations of enclosing modules
+    let tokens = translate_items(items, Some(registry))?;
+
+    let expanded = quote! {
+        #(#attrs)*
+        #vis mod #ident {
+            #tokens
+        }
+    };
+
+    Ok(expanded)
+}
+
+fn translate_use(use_: &ItemUse) -> TokenStream {
+    // return as it is
+    quote! { #use_ }
+}
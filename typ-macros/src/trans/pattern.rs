@@ -0,0 +1,555 @@
+use super::*;
+use proc_macro2::{Group, TokenTree};
+
+// The patterns of match arms form a space of types. Arms that overlap with previous arms
+// are refined to the rest of the space, so that the generated impls do not overlap.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ctor {
+    Named(String),
+    Tuple(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct PatNode {
+    pub binding: Option<Shared<Variable>>,
+    pub kind: PatKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum PatKind {
+    Wild,
+    Ctor {
+        ctor: Ctor,
+        path: Option<PathVar>,
+        args: Vec<PatNode>,
+    },
+    Opaque(TypeVar),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlap {
+    Yes,
+    No,
+    Unknown,
+}
+
+impl PatNode {
+    pub fn wild() -> Self {
+        Self {
+            binding: None,
+            kind: PatKind::Wild,
+        }
+    }
+
+    pub fn from_type_var(ty: &TypeVar, bindings: &IndexSet<Shared<Variable>>) -> Self {
+        let opaque = || Self {
+            binding: None,
+            kind: PatKind::Opaque(ty.clone()),
+        };
+
+        match ty {
+            TypeVar::Var(var) if bindings.contains(var) => Self {
+                binding: Some(var.clone()),
+                kind: PatKind::Wild,
+            },
+            TypeVar::Path(TypePathVar { qself: None, path }) => {
+                let last = path.segments.last().unwrap();
+                let args = match &last.arguments {
+                    PathArgumentsVar::None => vec![],
+                    PathArgumentsVar::AngleBracketed(args) => args
+                        .iter()
+                        .map(|arg| Self::from_type_var(arg, bindings))
+                        .collect(),
+                    PathArgumentsVar::Parenthesized(_) => return opaque(),
+                };
+                let ctor = Ctor::Named(last.ident.to_string());
+                let mut path = path.clone();
+                path.segments.last_mut().unwrap().arguments = PathArgumentsVar::None;
+
+                Self {
+                    binding: None,
+                    kind: PatKind::Ctor {
+                        ctor,
+                        path: Some(path),
+                        args,
+                    },
+                }
+            }
+            TypeVar::Tuple(TypeTupleVar { elems }) => {
+                let args: Vec<_> = elems
+                    .iter()
+                    .map(|elem| Self::from_type_var(elem, bindings))
+                    .collect();
+                Self {
+                    binding: None,
+                    kind: PatKind::Ctor {
+                        ctor: Ctor::Tuple(args.len()),
+                        path: None,
+                        args,
+                    },
+                }
+            }
+            _ => opaque(),
+        }
+    }
+
+    pub fn overlap(&self, other: &Self) -> Overlap {
+        match (&self.kind, &other.kind) {
+            (PatKind::Wild, _) | (_, PatKind::Wild) => Overlap::Yes,
+            (PatKind::Opaque(lhs), PatKind::Opaque(rhs)) => {
+                if lhs == rhs {
+                    Overlap::Yes
+                } else {
+                    Overlap::Unknown
+                }
+            }
+            (PatKind::Opaque(_), _) | (_, PatKind::Opaque(_)) => Overlap::Unknown,
+            (
+                PatKind::Ctor {
+                    ctor: lctor,
+                    args: largs,
+                    ..
+                },
+                PatKind::Ctor {
+                    ctor: rctor,
+                    args: rargs,
+                    ..
+                },
+            ) => {
+                if lctor != rctor {
+                    return Overlap::No;
+                }
+                if largs.len() != rargs.len() {
+                    return Overlap::Unknown;
+                }
+
+                largs
+                    .iter()
+                    .zip(rargs.iter())
+                    .fold(Overlap::Yes, |overlap, (lhs, rhs)| {
+                        match (overlap, lhs.overlap(rhs)) {
+                            (Overlap::No, _) | (_, Overlap::No) => Overlap::No,
+                            (Overlap::Unknown, _) | (_, Overlap::Unknown) => Overlap::Unknown,
+                            (Overlap::Yes, Overlap::Yes) => Overlap::Yes,
+                        }
+                    })
+            }
+        }
+    }
+
+    // the part of self covered by other, assuming they overlap
+    fn intersect(&self, other: &Self) -> Self {
+        match (&self.kind, &other.kind) {
+            (_, PatKind::Wild) => self.clone(),
+            (PatKind::Wild, _) => Self {
+                binding: self.binding.clone(),
+                kind: other.without_bindings().kind,
+            },
+            (
+                PatKind::Ctor { ctor, path, args },
+                PatKind::Ctor {
+                    args: other_args, ..
+                },
+            ) => Self {
+                binding: self.binding.clone(),
+                kind: PatKind::Ctor {
+                    ctor: ctor.clone(),
+                    path: path.clone(),
+                    args: args
+                        .iter()
+                        .zip(other_args.iter())
+                        .map(|(lhs, rhs)| lhs.intersect(rhs))
+                        .collect(),
+                },
+            },
+            _ => self.clone(),
+        }
+    }
+
+    pub fn binding_vars(&self) -> IndexSet<Shared<Variable>> {
+        let mut vars = IndexSet::new();
+        self.collect_binding_vars(&mut vars);
+        vars
+    }
+
+    fn collect_binding_vars(&self, vars: &mut IndexSet<Shared<Variable>>) {
+        if let Some(var) = &self.binding {
+            vars.insert(var.clone());
+        }
+        if let PatKind::Ctor { args, .. } = &self.kind {
+            args.iter().for_each(|arg| arg.collect_binding_vars(vars));
+        }
+    }
+
+    fn without_bindings(&self) -> Self {
+        let kind = match &self.kind {
+            PatKind::Ctor { ctor, path, args } => PatKind::Ctor {
+                ctor: ctor.clone(),
+                path: path.clone(),
+                args: args.iter().map(|arg| arg.without_bindings()).collect(),
+            },
+            kind => kind.clone(),
+        };
+        Self {
+            binding: None,
+            kind,
+        }
+    }
+
+    // the part of self not covered by any of others
+    pub fn exclude<'p>(
+        &self,
+        others: impl IntoIterator<Item = &'p Self>,
+        families: &Families,
+    ) -> syn::Result<Vec<Self>> {
+        let mut rest = vec![self.clone()];
+        for other in others {
+            rest = rest
+                .into_iter()
+                .map(|node| match node.overlap(other) {
+                    Overlap::Yes => node.subtract(other, families),
+                    Overlap::No | Overlap::Unknown => Ok(vec![node]),
+                })
+                .collect::<syn::Result<Vec<_>>>()?
+                .into_iter()
+                .flatten()
+                .collect();
+        }
+        Ok(rest)
+    }
+
+    // the part of self not covered by other, assuming they overlap
+    pub fn subtract(&self, other: &Self, families: &Families) -> syn::Result<Vec<Self>> {
+        self.subtract_at(other, None, families)
+    }
+
+    fn subtract_at(
+        &self,
+        other: &Self,
+        parent: Option<(&Ctor, usize)>,
+        families: &Families,
+    ) -> syn::Result<Vec<Self>> {
+        match (&self.kind, &other.kind) {
+            (_, PatKind::Wild) => Ok(vec![]),
+            (PatKind::Wild, PatKind::Ctor { ctor, path, .. }) => {
+                // split the wildcard into constructors of the type family
+                let mut rest = vec![];
+                for (family_ctor, family_path, arity) in families.family(ctor, path, parent)? {
+                    let node = Self {
+                        binding: self.binding.clone(),
+                        kind: PatKind::Ctor {
+                            ctor: family_ctor.clone(),
+                            path: family_path,
+                            args: vec![Self::wild(); arity],
+                        },
+                    };
+                    if &family_ctor == ctor {
+                        rest.extend(node.subtract_at(other, parent, families)?);
+                    } else {
+                        rest.push(node);
+                    }
+                }
+                Ok(rest)
+            }
+            (PatKind::Opaque(_), PatKind::Opaque(_)) => Ok(vec![]),
+            (
+                PatKind::Ctor { ctor, path, args },
+                PatKind::Ctor {
+                    args: other_args, ..
+                },
+            ) => {
+                // (a, b) - (c, d) = (a - c, b) + (a & c, b - d)
+                let mut rest = vec![];
+                for index in 0..args.len() {
+                    let arg_rest = args[index].subtract_at(
+                        &other_args[index],
+                        Some((ctor, index)),
+                        families,
+                    )?;
+
+                    for arg in arg_rest {
+                        let args: Vec<_> = args[0..index]
+                            .iter()
+                            .zip(other_args[0..index].iter())
+                            .map(|(lhs, rhs)| lhs.intersect(rhs))
+                            .chain(iter::once(arg))
+                            .chain(args[(index + 1)..].iter().cloned())
+                            .collect();
+                        rest.push(Self {
+                            binding: self.binding.clone(),
+                            kind: PatKind::Ctor {
+                                ctor: ctor.clone(),
+                                path: path.clone(),
+                                args,
+                            },
+                        });
+                    }
+                }
+                Ok(rest)
+            }
+            _ => Err(families.error(
+                "cannot exclude types matched by previous arms because of captured variables",
+            )),
+        }
+    }
+}
+
+pub struct Families<'a> {
+    pub registry: &'a Registry,
+    pub span: Span,
+    // the #[default] attribute can only be put on written arms
+    pub suggest_default: bool,
+}
+
+impl Families<'_> {
+    pub fn error(&self, message: &str) -> Error {
+        if self.suggest_default {
+            Error::new(
+                self.span,
+                format!("{}, consider adding the #[default] attribute", message),
+            )
+        } else {
+            Error::new(self.span, message)
+        }
+    }
+
+    // list the constructors of the closed type family the constructor belongs to
+    fn family(
+        &self,
+        ctor: &Ctor,
+        path: &Option<PathVar>,
+        parent: Option<(&Ctor, usize)>,
+    ) -> syn::Result<Vec<(Ctor, Option<PathVar>, usize)>> {
+        let name = match ctor {
+            Ctor::Tuple(len) => return Ok(vec![(ctor.clone(), None, *len)]),
+            Ctor::Named(name) => name,
+        };
+
+        // types declared in the typ! block
+        let ident = format_ident!("{}", name);
+        if let Some(record) = self.registry.record(&ident) {
+            let records = match &record.enum_ident {
+                Some(enum_ident) => self.registry.variants(enum_ident),
+                None => vec![record],
+            };
+            let family = records
+                .into_iter()
+                .map(|record| {
+                    let path = path.clone().map(|mut path| {
+                        path.segments.last_mut().unwrap().ident = record.ident.clone();
+                        path
+                    });
+                    (
+                        Ctor::Named(record.ident.to_string()),
+                        path,
+                        record.fields.len(),
+                    )
+                })
+                .collect();
+            return Ok(family);
+        }
+
+        // typenum types
+        let in_signed = matches!(
+            parent,
+            Some((Ctor::Named(parent), 0)) if parent == "PInt" || parent == "NInt"
+        );
+        let names: &[(&str, usize)] = match name.as_str() {
+            "B0" | "B1" => &[("B0", 0), ("B1", 0)],
+            "UTerm" | "UInt" if in_signed => &[("UInt", 2)],
+            "UTerm" | "UInt" => &[("UTerm", 0), ("UInt", 2)],
+            "Z0" | "PInt" | "NInt" => &[("Z0", 0), ("PInt", 1), ("NInt", 1)],
+            _ => {
+                return Err(self.error(&format!(
+                    "cannot enumerate types not matched by previous arms, `{}` is neither a typenum type nor declared in an enum in this typ! block",
+                    name
+                )))
+            }
+        };
+
+        let family = names
+            .iter()
+            .map(|(name, arity)| {
+                let path = typenum_path(name);
+                (Ctor::Named(name.to_string()), Some(path), *arity)
+            })
+            .collect();
+        Ok(family)
+    }
+
+    // trait bounds implied by the position in the parent constructor
+    pub fn implied_bounds(
+        &self,
+        parent: Option<(&Ctor, usize)>,
+        env: &mut Env,
+    ) -> syn::Result<Vec<TypeParamBoundVar>> {
+        let (name, index) = match parent {
+            Some((Ctor::Named(name), index)) => (name, index),
+            _ => return Ok(vec![]),
+        };
+
+        let ident = format_ident!("{}", name);
+        if let Some(record) = self.registry.record(&ident) {
+            return match record.field_types.get(index) {
+                Some(ty) => ty.parse_type_param_bounds_var(env),
+                None => Ok(vec![]),
+            };
+        }
+
+        let bounds = match (name.as_str(), index) {
+            ("UInt", 0) => vec![quote! { typenum::Unsigned }],
+            ("UInt", 1) => vec![quote! { typenum::Bit }],
+            ("PInt", 0) | ("NInt", 0) => {
+                vec![quote! { typenum::Unsigned }, quote! { typenum::NonZero }]
+            }
+            _ => vec![],
+        };
+        bounds
+            .into_iter()
+            .map(|tokens| syn::parse2::<TypeParamBound>(tokens)?.parse_type_param_bound_var(env))
+            .try_collect()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Rendered {
+    // generics for wildcards created by refinement
+    pub generics: Vec<Ident>,
+    // trait bounds implied by positions of wildcards
+    pub predicates: Vec<TokenStream>,
+    // generics of refined bindings and the refined types
+    pub replacements: HashMap<Ident, TokenStream>,
+    pub kept: Vec<Shared<Variable>>,
+    pub refined: Vec<Shared<Variable>>,
+}
+
+impl PatNode {
+    pub fn render(
+        &self,
+        env: &mut Env,
+        substitution: &IndexMap<Shared<Variable>, Ident>,
+        families: &Families,
+        rendered: &mut Rendered,
+    ) -> syn::Result<TokenStream> {
+        self.render_at(None, env, substitution, families, rendered)
+    }
+
+    fn render_at(
+        &self,
+        parent: Option<(&Ctor, usize)>,
+        env: &mut Env,
+        substitution: &IndexMap<Shared<Variable>, Ident>,
+        families: &Families,
+        rendered: &mut Rendered,
+    ) -> syn::Result<TokenStream> {
+        let tokens = match &self.kind {
+            PatKind::Wild => match &self.binding {
+                Some(var) => {
+                    rendered.kept.push(var.clone());
+                    let ident = &substitution[var];
+                    quote! { #ident }
+                }
+                None => {
+                    let ident = format_ident!(
+                        "{}GENERIC_{}",
+                        IDENT_PREFIX,
+                        substitution.len() + rendered.generics.len()
+                    );
+                    rendered.generics.push(ident.clone());
+                    quote! { #ident }
+                }
+            },
+            PatKind::Ctor { ctor, path, args } => {
+                let args: Vec<_> = args
+                    .iter()
+                    .enumerate()
+                    .map(|(index, arg)| {
+                        arg.render_at(Some((ctor, index)), env, substitution, families, rendered)
+                    })
+                    .try_collect()?;
+
+                match path {
+                    None => quote! { (#(#args,)*) },
+                    Some(path) => {
+                        let path = path.substitute(env, substitution);
+                        if args.is_empty() {
+                            quote! { #path }
+                        } else {
+                            quote! { #path< #(#args),* > }
+                        }
+                    }
+                }
+            }
+            PatKind::Opaque(ty) => {
+                let ty = ty.substitute(env, substitution);
+                quote! { #ty }
+            }
+        };
+
+        match (&self.kind, &self.binding) {
+            (PatKind::Wild, _) => {
+                // the wildcard must satisfy the trait bounds of the parent type
+                let bounds: Vec<_> = families
+                    .implied_bounds(parent, env)?
+                    .into_iter()
+                    .map(|bound| bound.substitute(env, substitution))
+                    .collect();
+                if !bounds.is_empty() {
+                    rendered.predicates.push(quote! { #tokens: #(#bounds)+* });
+                }
+            }
+            (_, Some(var)) => {
+                rendered.refined.push(var.clone());
+                rendered
+                    .replacements
+                    .insert(substitution[var].clone(), tokens.clone());
+            }
+            (_, None) => (),
+        }
+
+        Ok(tokens)
+    }
+}
+
+// replace identifiers in the token stream, including nested groups
+pub fn replace_idents(
+    tokens: TokenStream,
+    replacements: &HashMap<Ident, TokenStream>,
+) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tree| -> TokenStream {
+            match tree {
+                TokenTree::Ident(ident) => match replacements.get(&ident) {
+                    Some(tokens) => tokens.clone(),
+                    None => TokenTree::Ident(ident).into(),
+                },
+                TokenTree::Group(group) => {
+                    let stream = replace_idents(group.stream(), replacements);
+                    let mut new_group = Group::new(group.delimiter(), stream);
+                    new_group.set_span(group.span());
+                    TokenTree::Group(new_group).into()
+                }
+                tree => tree.into(),
+            }
+        })
+        .collect()
+}
+
+fn typenum_path(name: &str) -> PathVar {
+    let tokens = match name {
+        "B0" => quote! { typenum::B0 },
+        "B1" => quote! { typenum::B1 },
+        "UTerm" => quote! { typenum::uint::UTerm },
+        "UInt" => quote! { typenum::uint::UInt },
+        "Z0" => quote! { typenum::Z0 },
+        "PInt" => quote! { typenum::int::PInt },
+        "NInt" => quote! { typenum::int::NInt },
+        _ => unreachable!("please report bug: unknown typenum type"),
+    };
+    syn::parse2::<Path>(tokens)
+        .unwrap()
+        .parse_pure_path(&mut vec![])
+        .unwrap()
+}
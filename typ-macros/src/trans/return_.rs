@@ -0,0 +1,220 @@
+use super::*;
+
+pub fn translate_return_expr(
+    return_: &ExprReturn,
+    _env: &mut Env,
+    _items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    // early returns are lowered before translation, the rest are in unsupported places
+    Err(Error::new(
+        return_.span(),
+        "return is only supported in statements of the function body and their if branches",
+    ))
+}
+
+pub fn translate_break_expr(
+    break_: &ExprBreak,
+    _env: &mut Env,
+    _items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    Err(Error::new(
+        break_.span(),
+        "break is only supported in statements of the loop body and their if branches",
+    ))
+}
+
+pub fn translate_continue_expr(
+    continue_: &ExprContinue,
+    _env: &mut Env,
+    _items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    Err(Error::new(
+        continue_.span(),
+        "continue is only supported in statements of the loop body and their if branches",
+    ))
+}
+
+// statements that leave the block early
+#[derive(Debug, Clone, Copy)]
+enum Exit {
+    Return,
+    Loop,
+}
+
+impl Exit {
+    // the output of the block if the statement leaves it
+    fn value(self, expr: &Expr) -> Option<syn::Result<Expr>> {
+        let tokens = match (self, expr) {
+            (Exit::Return, Expr::Return(ExprReturn { expr, .. })) => match expr {
+                Some(expr) => quote! { #expr },
+                None => quote! { () },
+            },
+            (
+                Exit::Loop,
+                Expr::Break(ExprBreak {
+                    label: None, expr, ..
+                }),
+            ) => match expr {
+                Some(expr) => quote! { (true, #expr) },
+                None => quote! { (true, ()) },
+            },
+            (Exit::Loop, Expr::Continue(ExprContinue { label: None, .. })) => {
+                quote! { (false, ()) }
+            }
+            _ => return None,
+        };
+        Some(syn::parse2(tokens))
+    }
+}
+
+pub fn lower_return_block(block: &Block) -> syn::Result<Block> {
+    lower_exit_block(block, Exit::Return)
+}
+
+// the body evaluates to (true, value) on break, and (false, ()) to continue the loop
+pub fn lower_break_block(block: &Block) -> syn::Result<Block> {
+    let mut stmts = block.stmts.to_owned();
+    if let Some(last) = stmts.last_mut() {
+        if let Stmt::Expr(expr) = last {
+            *last = Stmt::Semi(expr.to_owned(), Default::default());
+        }
+    }
+    stmts.push(Stmt::Expr(syn::parse2(quote! { (false, ()) })?));
+
+    Ok(Block {
+        brace_token: block.brace_token,
+        stmts: lower_exit_stmts(&stmts, Exit::Loop)?,
+    })
+}
+
+fn lower_exit_block(block: &Block, exit: Exit) -> syn::Result<Block> {
+    Ok(Block {
+        brace_token: block.brace_token,
+        stmts: lower_exit_stmts(&block.stmts, exit)?,
+    })
+}
+
+// the statements after an if with early exits become its other branches
+fn lower_exit_stmts(stmts: &[Stmt], exit: Exit) -> syn::Result<Vec<Stmt>> {
+    let mut output = vec![];
+
+    for (index, stmt) in stmts.iter().enumerate() {
+        let expr = match stmt {
+            Stmt::Expr(expr) | Stmt::Semi(expr, _) => expr,
+            stmt => {
+                output.push(stmt.to_owned());
+                continue;
+            }
+        };
+        let rest = &stmts[(index + 1)..];
+
+        // the exit value becomes the output, and the rest is unreachable
+        if let Some(value) = exit.value(expr) {
+            output.push(Stmt::Expr(value?));
+            return Ok(output);
+        }
+
+        match expr {
+            Expr::If(if_) if if_contains_exit(if_, exit) => {
+                let is_tail = matches!(stmt, Stmt::Expr(_)) && rest.is_empty();
+                let rest = lower_exit_stmts(rest, exit)?;
+                let if_ = lower_exit_if(if_, &rest, is_tail, exit)?;
+                output.push(Stmt::Expr(Expr::If(if_)));
+                return Ok(output);
+            }
+            _ => output.push(stmt.to_owned()),
+        }
+    }
+
+    Ok(output)
+}
+
+fn lower_exit_if(if_: &ExprIf, rest: &[Stmt], is_tail: bool, exit: Exit) -> syn::Result<ExprIf> {
+    let then_branch = lower_exit_branch(&if_.then_branch, rest, is_tail, exit)?;
+    let else_branch = match &if_.else_branch {
+        Some((else_token, expr)) => {
+            let expr = match &**expr {
+                Expr::If(else_if) => Expr::If(lower_exit_if(else_if, rest, is_tail, exit)?),
+                Expr::Block(block_expr) => Expr::Block(ExprBlock {
+                    block: lower_exit_branch(&block_expr.block, rest, is_tail, exit)?,
+                    ..block_expr.to_owned()
+                }),
+                expr => return Err(Error::new(expr.span(), "unsupported else branch")),
+            };
+            (else_token.to_owned(), Box::new(expr))
+        }
+        None => (
+            Default::default(),
+            Box::new(syn::parse2(quote! { { #(#rest)* } })?),
+        ),
+    };
+
+    Ok(ExprIf {
+        then_branch,
+        else_branch: Some(else_branch),
+        ..if_.to_owned()
+    })
+}
+
+fn lower_exit_branch(
+    block: &Block,
+    rest: &[Stmt],
+    is_tail: bool,
+    exit: Exit,
+) -> syn::Result<Block> {
+    if stmts_diverge(&block.stmts, exit) {
+        lower_exit_block(block, exit)
+    } else if stmts_contain_exit(&block.stmts, exit) {
+        Err(Error::new(
+            block.span(),
+            "the branch must either exit on every path or not exit at all",
+        ))
+    } else if is_tail {
+        Ok(block.to_owned())
+    } else if rest.is_empty() {
+        // keep the output of the if statement as unit
+        syn::parse2(quote! { { #block () } })
+    } else {
+        syn::parse2(quote! { { #block #(#rest)* } })
+    }
+}
+
+fn stmts_diverge(stmts: &[Stmt], exit: Exit) -> bool {
+    stmts.iter().any(|stmt| match stmt {
+        Stmt::Expr(Expr::If(if_)) | Stmt::Semi(Expr::If(if_), _) => if_diverges(if_, exit),
+        Stmt::Expr(expr) | Stmt::Semi(expr, _) => exit.value(expr).is_some(),
+        _ => false,
+    })
+}
+
+fn if_diverges(if_: &ExprIf, exit: Exit) -> bool {
+    stmts_diverge(&if_.then_branch.stmts, exit)
+        && match &if_.else_branch {
+            Some((_, expr)) => match &**expr {
+                Expr::If(else_if) => if_diverges(else_if, exit),
+                Expr::Block(ExprBlock { block, .. }) => stmts_diverge(&block.stmts, exit),
+                _ => false,
+            },
+            None => false,
+        }
+}
+
+fn stmts_contain_exit(stmts: &[Stmt], exit: Exit) -> bool {
+    stmts.iter().any(|stmt| match stmt {
+        Stmt::Expr(Expr::If(if_)) | Stmt::Semi(Expr::If(if_), _) => if_contains_exit(if_, exit),
+        Stmt::Expr(expr) | Stmt::Semi(expr, _) => exit.value(expr).is_some(),
+        _ => false,
+    })
+}
+
+fn if_contains_exit(if_: &ExprIf, exit: Exit) -> bool {
+    stmts_contain_exit(&if_.then_branch.stmts, exit)
+        || match &if_.else_branch {
+            Some((_, expr)) => match &**expr {
+                Expr::If(else_if) => if_contains_exit(else_if, exit),
+                Expr::Block(ExprBlock { block, .. }) => stmts_contain_exit(&block.stmts, exit),
+                _ => false,
+            },
+            None => false,
+        }
+}
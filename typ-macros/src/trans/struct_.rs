@@ -0,0 +1,409 @@
+use super::*;
+
+pub fn translate_struct(struct_: &ItemStruct) -> syn::Result<TokenStream> {
+    let ItemStruct {
+        attrs,
+        vis,
+        ident,
+        generics,
+        fields,
+        ..
+    } = struct_;
+
+    // sanity check
+    check_no_generics(generics)?;
+
+    // generate struct item
+    let Record {
+        item,
+        generics,
+        predicates,
+    } = translate_record(attrs, vis, ident, fields)?;
+
+    // generate marker trait
+    let marker_name = format_ident!("{}Marker", ident);
+
+    let expanded = quote! {
+        #item
+
+        #vis trait #marker_name {}
+
+        #[allow(non_camel_case_types)]
+        impl< #(#generics),* > #marker_name for #ident< #(#generics),* >
+        where
+            #(#predicates),*
+        {}
+    };
+
+    Ok(expanded)
+}
+
+pub struct Record {
+    pub item: TokenStream,
+    pub generics: Vec<Ident>,
+    pub predicates: Vec<TokenStream>,
+}
+
+pub fn translate_record(
+    attrs: &[Attribute],
+    vis: &Visibility,
+    ident: &Ident,
+    fields: &Fields,
+) -> syn::Result<Record> {
+    // each field becomes a generic, and the field type becomes trait bounds on the generic
+    let params: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| -> syn::Result<_> {
+            let Field {
+                ident: field_ident,
+                ty,
+                ..
+            } = field;
+            let generic = match field_ident {
+                Some(field_ident) => field_ident.to_owned(),
+                None => format_ident!("T{}", index),
+            };
+            let bounds = ty_to_trait_bounds(ty)?;
+            Ok((generic, bounds))
+        })
+        .try_collect()?;
+
+    let generics: Vec<_> = params
+        .iter()
+        .map(|(generic, _)| generic.to_owned())
+        .collect();
+    let predicates: Vec<_> = params
+        .iter()
+        .filter(|(_, bounds)| !bounds.is_empty())
+        .map(|(generic, bounds)| quote! { #generic: #(#bounds)+* })
+        .collect();
+
+    let item = match fields {
+        Fields::Unit => quote! {
+            #(#attrs)*
+            #vis struct #ident;
+        },
+        Fields::Unnamed(_) => {
+            let field_vis: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+            quote! {
+                #(#attrs)*
+                #[allow(non_camel_case_types)]
+                #vis struct #ident< #(#generics),* >(
+                    #(#field_vis core::marker::PhantomData<#generics>),*
+                )
+                where
+                    #(#predicates),*;
+            }
+        }
+        Fields::Named(_) => {
+            let field_vis: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+            quote! {
+                #(#attrs)*
+                #[allow(non_camel_case_types)]
+                #vis struct #ident< #(#generics),* >
+                where
+                    #(#predicates),*
+                {
+                    #(#field_vis #generics: core::marker::PhantomData<#generics>),*
+                }
+            }
+        }
+    };
+
+    // generate field accessors
+    let accessors: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .zip(generics.iter())
+        .map(|((index, field), generic)| {
+            let member = match &field.ident {
+                Some(field_ident) => Member::Named(field_ident.to_owned()),
+                None => Member::Unnamed(index.into()),
+            };
+            let trait_name = field_trait_name(&member);
+
+            quote! {
+                pub trait #trait_name {
+                    type Output;
+                }
+
+                impl< #(#generics),* > #trait_name for #ident< #(#generics),* >
+                where
+                    #(#predicates),*
+                {
+                    type Output = #generic;
+                }
+            }
+        })
+        .collect();
+
+    let item = if accessors.is_empty() {
+        item
+    } else {
+        let mod_name = record_mod_name(ident);
+        quote! {
+            #item
+
+            #[allow(non_snake_case, non_camel_case_types)]
+            mod #mod_name {
+                use super::*;
+
+                #(#accessors)*
+            }
+        }
+    };
+
+    Ok(Record {
+        item,
+        generics,
+        predicates,
+    })
+}
+
+pub fn translate_field_expr(
+    field: &ExprField,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprField { base, member, .. } = field;
+
+    let base_ty = translate_expr(base, env, items)?;
+    let record_ident = resolve_field_owner(&base_ty, member, env)?;
+
+    let trait_ = PathVar {
+        segments: vec![
+            SegmentVar {
+                ident: record_mod_name(&record_ident),
+                arguments: PathArgumentsVar::None,
+            },
+            SegmentVar {
+                ident: field_trait_name(member),
+                arguments: PathArgumentsVar::None,
+            },
+        ],
+    };
+    let path = {
+        let mut path = trait_.clone();
+        path.segments.push(SegmentVar {
+            ident: format_ident!("Output"),
+            arguments: PathArgumentsVar::None,
+        });
+        path
+    };
+    let output = TypeVar::Path(TypePathVar {
+        qself: Some(QSelfVar {
+            ty: Box::new(base_ty.clone()),
+            position: trait_.segments.len(),
+        }),
+        path,
+    });
+    let predicate = WherePredicateVar::Type(PredicateTypeVar {
+        bounded_ty: base_ty,
+        bounds: vec![TypeParamBoundVar::Trait(TraitBoundVar {
+            modifier: TraitBoundModifierVar::None,
+            path: trait_,
+        })],
+    });
+
+    env.insert_predicate(predicate);
+    Ok(output)
+}
+
+pub fn translate_struct_expr(
+    struct_: &ExprStruct,
+    env: &mut Env,
+    items: &mut Vec<Item>,
+) -> syn::Result<TypeVar> {
+    let ExprStruct {
+        path, fields, rest, ..
+    } = struct_;
+
+    // sanity check
+    if let Some(rest) = rest {
+        return Err(Error::new(
+            rest.span(),
+            "struct update syntax is not supported",
+        ));
+    }
+
+    let record_ident = &path.segments.last().unwrap().ident;
+    let (record_fields, field_types) = match env.registry().record(record_ident) {
+        Some(record) => (record.fields.clone(), record.field_types.clone()),
+        None => {
+            return Err(Error::new(
+                path.span(),
+                "the struct is not declared in this typ! block",
+            ))
+        }
+    };
+
+    // match field values to declared fields
+    let mut values = HashMap::new();
+    for FieldValue { member, expr, .. } in fields.iter() {
+        if !record_fields.contains(member) {
+            return Err(Error::new(member.span(), "no such field in the struct"));
+        }
+        if values.contains_key(member) {
+            return Err(Error::new(
+                member.span(),
+                "the field is specified more than once",
+            ));
+        }
+        let value = translate_expr(expr, env, items)?;
+        values.insert(member.to_owned(), value);
+    }
+
+    let missing: Vec<_> = record_fields
+        .iter()
+        .filter(|member| !values.contains_key(member))
+        .map(|member| format!("`{}`", quote! { #member }))
+        .collect();
+    if !missing.is_empty() {
+        return Err(Error::new(
+            path.span(),
+            format!("missing fields {}", missing.join(", ")),
+        ));
+    }
+
+    // place values in declaration order
+    let args: Vec<_> = record_fields
+        .iter()
+        .map(|member| values.remove(member).unwrap())
+        .collect();
+
+    // insert trait bounds on field values
+    for (value, ty) in args.iter().zip(field_types.iter()) {
+        let bounds = ty.parse_type_param_bounds_var(env)?;
+        if !bounds.is_empty() {
+            env.insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
+                bounded_ty: value.clone(),
+                bounds,
+            }));
+        }
+    }
+
+    let mut path = path.parse_path_var(env)?;
+    let last = path.segments.last_mut().unwrap();
+    if let PathArgumentsVar::None = last.arguments {
+        if !args.is_empty() {
+            last.arguments = PathArgumentsVar::AngleBracketed(args);
+        }
+    } else {
+        return Err(Error::new(
+            struct_.path.span(),
+            "type parameters are not allowed in struct expression",
+        ));
+    }
+
+    Ok(TypeVar::Path(TypePathVar { qself: None, path }))
+}
+
+fn resolve_field_owner(base_ty: &TypeVar, member: &Member, env: &Env) -> syn::Result<Ident> {
+    let candidates = env.registry().records_with_field(member);
+
+    let record = match candidates.as_slice() {
+        [] => {
+            return Err(Error::new(
+                member.span(),
+                "no struct declared in this typ! block has this field",
+            ))
+        }
+        [record] => *record,
+        _ => {
+            // disambiguate by the type or the trait bounds of the value
+            let matched: Vec<_> = candidates
+                .iter()
+                .filter(|record| is_record_of(record, base_ty, env))
+                .copied()
+                .collect();
+
+            match matched.as_slice() {
+                [record] => *record,
+                _ => {
+                    let names = candidates
+                        .iter()
+                        .map(|record| format!("`{}`", record.ident))
+                        .join(", ");
+                    return Err(Error::new(
+                        member.span(),
+                        format!(
+                            "ambiguous field, it is declared in {}. Add a marker trait bound on the value to disambiguate",
+                            names
+                        ),
+                    ));
+                }
+            }
+        }
+    };
+
+    Ok(record.ident.to_owned())
+}
+
+fn is_record_of(record: &RecordDecl, ty: &TypeVar, env: &Env) -> bool {
+    let marker_name = format_ident!("{}Marker", record.ident);
+    let has_bound = env.trait_bounds(ty).iter().any(|bound| match bound {
+        TypeParamBoundVar::Trait(TraitBoundVar { path, .. }) => {
+            let ident = &path.segments.last().unwrap().ident;
+            ident == &marker_name || Some(ident) == record.enum_ident.as_ref()
+        }
+    });
+
+    has_bound
+        || match ty {
+            TypeVar::Var(var) => match &var.value {
+                Some(value) => is_record_of(record, value, env),
+                None => false,
+            },
+            TypeVar::Path(TypePathVar { qself: None, path }) => {
+                path.segments.last().unwrap().ident == record.ident
+            }
+            _ => false,
+        }
+}
+
+pub fn record_mod_name(ident: &Ident) -> Ident {
+    format_ident!("{}mod_{}", IDENT_PREFIX, ident)
+}
+
+pub fn field_trait_name(member: &Member) -> Ident {
+    match member {
+        Member::Named(ident) => format_ident!("{}Field_{}", IDENT_PREFIX, ident),
+        Member::Unnamed(index) => format_ident!("{}Field_{}", IDENT_PREFIX, index.index),
+    }
+}
+
+pub fn ty_to_trait_bounds(ty: &Type) -> syn::Result<Vec<TypeParamBound>> {
+    match ty {
+        Type::Infer(_) => Ok(vec![]),
+        Type::Path(TypePath { qself: None, path }) => {
+            let bound: TraitBound = syn::parse2(quote! { #path })?;
+            Ok(vec![TypeParamBound::Trait(bound)])
+        }
+        Type::TraitObject(TypeTraitObject {
+            bounds, dyn_token, ..
+        }) => {
+            if let Some(token) = dyn_token {
+                return Err(Error::new(token.span(), "remove the dyn token"));
+            }
+            Ok(bounds.iter().cloned().collect())
+        }
+        _ => Err(Error::new(ty.span(), "not trait bounds")),
+    }
+}
+
+pub fn check_no_generics(generics: &Generics) -> syn::Result<()> {
+    if let Some(param) = generics.params.first() {
+        return Err(Error::new(
+            param.span(),
+            "generics are not allowed, the fields are the generics of the type",
+        ));
+    }
+    if let Some(where_clause) = &generics.where_clause {
+        return Err(Error::new(
+            where_clause.span(),
+            "where clause is not allowed, put the trait bounds on fields instead",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use super::*;
+
+pub fn translate_trait(trait_: &ItemTrait, registry: &Rc<Registry>) -> syn::Result<TokenStream> {
+    let ItemTrait {
+        attrs,
+        vis,
+        unsafety,
+        auto_token,
+        ident: trait_name,
+        generics,
+        supertraits,
+        items,
+        ..
+    } = trait_;
+
+    // sanity check
+    if let Some(unsafe_) = unsafety {
+        return Err(Error::new(
+            unsafe_.span(),
+            "unsafe keyword is not supported",
+        ));
+    }
+    if let Some(auto) = auto_token {
+        return Err(Error::new(auto.span(), "auto trait is not supported"));
+    }
+    if let Some(param) = generics.params.first() {
+        return Err(Error::new(
+            param.span(),
+            "generics on trait are not supported",
+        ));
+    }
+    if let Some(where_clause) = &generics.where_clause {
+        return Err(Error::new(
+            where_clause.span(),
+            "where clause on trait is not supported",
+        ));
+    }
+
+    // each method becomes a trait next to the marker trait
+    let (method_tokens, alias_tokens): (Vec<_>, Vec<_>) = items
+        .iter()
+        .map(|item| match item {
+            TraitItem::Method(method) => translate_trait_method(vis, method, registry),
+            _ => Err(Error::new(item.span(), "unsupported item")),
+        })
+        .try_collect::<_, Vec<_>, _>()?
+        .into_iter()
+        .unzip();
+
+    let supertraits = if supertraits.is_empty() {
+        None
+    } else {
+        Some(quote! { : #supertraits })
+    };
+
+    let method_names: Vec<_> = items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Method(method) => Some(&method.sig.ident),
+            _ => None,
+        })
+        .collect();
+    let mod_name = format_ident!("{}mod_{}", IDENT_PREFIX, trait_name);
+
+    let expanded = quote! {
+        #(#attrs)*
+        #vis trait #trait_name #supertraits {}
+
+        #vis use #mod_name::{ #(#method_names),* };
+        #(#alias_tokens)*
+
+        #[allow(non_snake_case)]
+        mod #mod_name {
+            use super::*;
+
+            #(#method_tokens)*
+        }
+    };
+
+    Ok(expanded)
+}
+
+fn translate_trait_method(
+    vis: &Visibility,
+    method: &TraitItemMethod,
+    registry: &Rc<Registry>,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let TraitItemMethod { sig, default, .. } = method;
+    let Signature {
+        ident: fn_name,
+        generics: fn_generics,
+        inputs,
+        output,
+        constness,
+        asyncness,
+        unsafety,
+        variadic,
+        ..
+    } = sig;
+
+    // sanity checks
+    if let Some(default) = default {
+        return Err(Error::new(
+            default.span(),
+            "default implementation is not supported",
+        ));
+    }
+
+    if let Some(const_) = constness {
+        return Err(Error::new(const_.span(), "the keyword is not supported"));
+    }
+
+    if let Some(async_) = asyncness {
+        return Err(Error::new(async_.span(), "the keyword is not supported"));
+    }
+
+    if let Some(unsafe_) = unsafety {
+        return Err(Error::new(unsafe_.span(), "the keyword is not supported"));
+    }
+
+    if let Some(var) = variadic {
+        return Err(Error::new(var.span(), "variadic argument is not supported"));
+    }
+
+    match inputs.first() {
+        Some(FnArg::Receiver(receiver)) => {
+            if receiver.reference.is_some() {
+                return Err(Error::new(
+                    receiver.span(),
+                    r#"referenced receiver "&self" is not supported, use "self" instead"#,
+                ));
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                sig.span(),
+                "functions inside trait must have a self receiver",
+            ))
+        }
+    }
+
+    let mut env = Env::new(fn_name.clone(), registry.clone());
+
+    // insert free quantifiers from fn generics
+    for param in fn_generics.params.iter() {
+        match param {
+            GenericParam::Type(TypeParam { ident, .. }) => {
+                env.insert_free_quantifier(ident.to_owned());
+            }
+            GenericParam::Lifetime(lifetime) => {
+                return Err(Error::new(lifetime.span(), "lifetime is not supported"))
+            }
+            GenericParam::Const(const_) => {
+                return Err(Error::new(const_.span(), "const generic is not supported"))
+            }
+        }
+    }
+
+    // translate argument and output types to trait bounds, where explicit generics come first
+    let arg_bounds: Vec<_> = iter::repeat_n(Ok(vec![]), explicit_generics(sig).len())
+        .chain(
+            inputs
+                .iter()
+                .filter_map(|arg| match arg {
+                    FnArg::Typed(pat_type) => Some(pat_type),
+                    FnArg::Receiver(_) => None,
+                })
+                .map(|PatType { ty, .. }| ty.parse_type_param_bounds_var(&mut env)),
+        )
+        .try_collect()?;
+
+    let output_bounds = match output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => Some(ty.parse_type_param_bounds_var(&mut env)?),
+    };
+
+    let subsitution: IndexMap<_, _> = env
+        .free_quantifiers()
+        .into_iter()
+        .enumerate()
+        .map(|(index, var)| (var, format_ident!("{}GENERIC_{}", IDENT_PREFIX, index)))
+        .collect();
+
+    let trait_item = translate_fn_trait(
+        fn_name,
+        &arg_bounds,
+        output_bounds.as_ref(),
+        &env,
+        &subsitution,
+    )?;
+
+    // generate type alias
+    let args: Vec<_> = (0..arg_bounds.len())
+        .map(|idx| format_ident!("{}ARG_{}", IDENT_PREFIX, idx))
+        .collect();
+    let self_arg = format_ident!("{}SELF", IDENT_PREFIX);
+    let type_name = format_ident!("{}Op", fn_name);
+
+    let alias = quote! {
+        #vis type #type_name<#self_arg, #(#args),*> = < #self_arg as #fn_name <#(#args),*> > :: Output;
+    };
+
+    Ok((quote! { #trait_item }, alias))
+}
@@ -5,6 +5,11 @@ pub fn translate_unary_expr(
     scope: &mut Env,
     items: &mut Vec<Item>,
 ) -> syn::Result<TypeVar> {
+    // fold negative literals, so that they can be used as loop states
+    if let (UnOp::Neg(_), Expr::Lit(ExprLit { lit, .. })) = (op, &**expr) {
+        return translate_lit(lit, true);
+    }
+
     let operand = translate_expr(expr, scope, items)?;
 
     let output = match op {
@@ -279,9 +279,10 @@ impl ParseTypeVar for Type {
                     _,
                 ) => {
                     let ty = ty.parse_type_var(env)?;
+                    let position = rebase_qself_position(path, *position);
                     let path = path.parse_path_var(env)?;
                     let trait_ = PathVar {
-                        segments: path.segments[0..(*position)].iter().cloned().collect(),
+                        segments: path.segments[0..position].iter().cloned().collect(),
                     };
 
                     env.insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
@@ -295,7 +296,7 @@ impl ParseTypeVar for Type {
                     TypeVar::Path(TypePathVar {
                         qself: Some(QSelfVar {
                             ty: Box::new(ty),
-                            position: *position,
+                            position,
                         }),
                         path,
                     })
@@ -362,9 +363,10 @@ impl ParseTypeVar for Pat {
                 ..
             }) => {
                 let ty = ty.parse_type_var(env)?;
+                let position = rebase_qself_position(path, *position);
                 let path = path.parse_path_var(env)?;
                 let trait_ = PathVar {
-                    segments: path.segments[0..(*position)].iter().cloned().collect(),
+                    segments: path.segments[0..position].iter().cloned().collect(),
                 };
 
                 env.insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
@@ -378,7 +380,7 @@ impl ParseTypeVar for Pat {
                 Ok(TypeVar::Path(TypePathVar {
                     qself: Some(QSelfVar {
                         ty: Box::new(ty),
-                        position: *position,
+                        position,
                     }),
                     path,
                 }))
@@ -424,9 +426,10 @@ impl ParseTypeVar for ExprPath {
         let var = match (qself, path.get_ident()) {
             (Some(QSelf { ty, position, .. }), _) => {
                 let ty = ty.parse_type_var(env)?;
+                let position = rebase_qself_position(path, *position);
                 let path = path.parse_path_var(env)?;
                 let trait_ = PathVar {
-                    segments: path.segments[0..(*position)].iter().cloned().collect(),
+                    segments: path.segments[0..position].iter().cloned().collect(),
                 };
 
                 env.insert_predicate(WherePredicateVar::Type(PredicateTypeVar {
@@ -440,7 +443,7 @@ impl ParseTypeVar for ExprPath {
                 TypeVar::Path(TypePathVar {
                     qself: Some(QSelfVar {
                         ty: Box::new(ty),
-                        position: *position,
+                        position,
                     }),
                     path,
                 })
@@ -468,14 +471,39 @@ pub trait ParsePathVar {
 impl ParsePathVar for Path {
     fn parse_path_var(&self, env: &mut Env) -> syn::Result<PathVar> {
         let Path { segments, .. } = self;
-        let segments: Vec<_> = segments
+        let mut segments: Vec<_> = segments
             .iter()
             .map(|segment| segment.parse_segment_var(env))
             .try_collect()?;
+        rebase_relative_path(&mut segments);
         Ok(PathVar { segments })
     }
 }
 
+// The translated items are placed in a child module of where the path is written.
+// Relative paths are rebased on the parent module, so that "self::A" becomes "super::A"
+// and "super::A" becomes "super::super::A".
+pub fn rebase_relative_path(segments: &mut Vec<SegmentVar>) {
+    if segments.len() <= 1 {
+        return;
+    }
+
+    let first = &mut segments[0];
+    if first.ident == "self" {
+        first.ident = Ident::new("super", first.ident.span());
+    } else if first.ident == "super" {
+        let super_ = first.clone();
+        segments.insert(0, super_);
+    }
+}
+
+pub fn rebase_qself_position(path: &Path, position: usize) -> usize {
+    match path.segments.first() {
+        Some(first) if path.segments.len() > 1 && first.ident == "super" => position + 1,
+        _ => position,
+    }
+}
+
 pub trait ParseSegmentVar {
     fn parse_segment_var(&self, env: &mut Env) -> syn::Result<SegmentVar>;
 }
@@ -765,7 +793,8 @@ impl Subsitution for TypeTupleVar {
             .iter()
             .map(|elem| elem.substitute(env, substitution))
             .collect();
-        syn::parse2(quote! { (#(#elems),*) }).unwrap()
+        // the trailing comma keeps a single element tuple from being a parenthesized type
+        syn::parse2(quote! { (#(#elems,)*) }).unwrap()
     }
 }
 
@@ -867,6 +896,41 @@ impl Subsitution for TypeParamBoundVar {
     }
 }
 
+// variable lookup
+
+impl TypeVar {
+    pub fn contains_variable(&self, var: &Shared<Variable>) -> bool {
+        match self {
+            TypeVar::Var(other) => match &other.value {
+                Some(value) => value.contains_variable(var),
+                None => other == var,
+            },
+            TypeVar::Path(TypePathVar { qself, path }) => {
+                qself
+                    .as_ref()
+                    .is_some_and(|QSelfVar { ty, .. }| ty.contains_variable(var))
+                    || path.contains_variable(var)
+            }
+            TypeVar::Tuple(TypeTupleVar { elems }) => {
+                elems.iter().any(|elem| elem.contains_variable(var))
+            }
+        }
+    }
+}
+
+impl PathVar {
+    pub fn contains_variable(&self, var: &Shared<Variable>) -> bool {
+        self.segments
+            .iter()
+            .any(|SegmentVar { arguments, .. }| match arguments {
+                PathArgumentsVar::None => false,
+                PathArgumentsVar::AngleBracketed(args) | PathArgumentsVar::Parenthesized(args) => {
+                    args.iter().any(|arg| arg.contains_variable(var))
+                }
+            })
+    }
+}
+
 // types
 
 #[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]